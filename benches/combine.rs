@@ -0,0 +1,38 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use rand::RngCore;
+use shamirs::{combine, combine_shares, split, split_shares};
+
+/// The sizes of the secrets, in bytes.
+const SIZES: [usize; 2] = [1 << 20, 4 << 20];
+/// The thresholds, each combined from exactly `threshold` shares.
+const THRESHOLDS: [usize; 3] = [2, 5, 10];
+
+fn bench_combine(c: &mut Criterion) {
+    let mut group = c.benchmark_group("combine");
+    group.sample_size(10);
+
+    for size in SIZES {
+        let mut secret = vec![0u8; size];
+        rand::thread_rng().fill_bytes(&mut secret);
+        group.throughput(Throughput::Bytes(size as u64));
+
+        for threshold in THRESHOLDS {
+            let parameter = format!("{}MiB/t={threshold}", size >> 20);
+
+            let shares = split_shares(&secret, threshold, threshold).expect("split failed");
+            group.bench_with_input(BenchmarkId::new("combine_shares", &parameter), &shares, |b, shares| {
+                b.iter(|| combine_shares(shares).expect("combine failed"))
+            });
+
+            let shares = split(&secret, threshold, threshold).expect("split failed");
+            group.bench_with_input(BenchmarkId::new("combine", &parameter), &shares, |b, shares| {
+                b.iter(|| combine(shares).expect("combine failed"))
+            });
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_combine);
+criterion_main!(benches);
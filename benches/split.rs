@@ -0,0 +1,37 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use rand::RngCore;
+use shamirs::{split, split_shares};
+
+/// The sizes of the secrets, in bytes.
+const SIZES: [usize; 2] = [1 << 20, 4 << 20];
+/// The thresholds, each split into `PARTS` shares.
+const THRESHOLDS: [usize; 3] = [2, 5, 10];
+/// The total number of shares.
+const PARTS: usize = 10;
+
+fn bench_split(c: &mut Criterion) {
+    let mut group = c.benchmark_group("split");
+    group.sample_size(10);
+
+    for size in SIZES {
+        let mut secret = vec![0u8; size];
+        rand::thread_rng().fill_bytes(&mut secret);
+        group.throughput(Throughput::Bytes(size as u64));
+
+        for threshold in THRESHOLDS {
+            let parameter = format!("{}MiB/n={PARTS}/t={threshold}", size >> 20);
+
+            group.bench_with_input(BenchmarkId::new("split_shares", &parameter), &secret, |b, secret| {
+                b.iter(|| split_shares(secret, PARTS, threshold).expect("split failed"))
+            });
+            group.bench_with_input(BenchmarkId::new("split", &parameter), &secret, |b, secret| {
+                b.iter(|| split(secret, PARTS, threshold).expect("split failed"))
+            });
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_split);
+criterion_main!(benches);
@@ -0,0 +1,42 @@
+//! Exercises the public API of `shamirs` in a `no_std` environment with `alloc`,
+//! where randomness is provided by the caller, e.g., a hardware generator.
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use rand::{CryptoRng, RngCore};
+use shamirs::{
+    combine_array, combine_checked, combine_robust, combine_shares, refresh_shares_with_rng, split_checked_with_rng,
+    split_into_with_rng, split_shares_with_rng, verify_consistency, Error, Share,
+};
+
+/// Splits the secret, refreshes and encodes the shares, and reconstructs it from the decoded shares.
+pub fn round_trip<R: RngCore + CryptoRng>(secret: &[u8], rng: &mut R) -> Result<Vec<u8>, Error> {
+    let shares = split_shares_with_rng(secret, 5, 3, rng)?;
+    let shares = refresh_shares_with_rng(&shares, 3, rng)?;
+    verify_consistency(&shares, 3)?;
+
+    let encoded: Vec<Vec<u8>> = shares.iter().map(Share::to_bytes).collect();
+    let decoded = encoded
+        .iter()
+        .map(|bytes| Share::from_bytes(bytes))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let secret = combine_shares(&decoded[..3])?;
+    combine_robust(&decoded, 3)?;
+    Ok(secret)
+}
+
+/// Splits the secret in checked mode and verifies its reconstruction.
+pub fn checked_round_trip<R: RngCore + CryptoRng>(secret: &[u8], rng: &mut R) -> Result<Vec<u8>, Error> {
+    let shares = split_checked_with_rng(secret, 5, 3, rng)?;
+    combine_checked(&shares[2..])
+}
+
+/// Splits and reconstructs a 32-byte key without allocating.
+pub fn fixed_round_trip<R: RngCore + CryptoRng>(secret: &[u8; 32], rng: &mut R) -> Result<[u8; 32], Error> {
+    let mut shares = [[0u8; 33]; 5];
+    split_into_with_rng(secret, 3, &mut shares, rng)?;
+    combine_array(&shares[1..4])
+}
@@ -42,7 +42,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Finally, print the original and the recovered secret in hex.
     println!();
-    println!("initial:   {}", hex::encode(&secret));
+    println!("initial:   {}", hex::encode(secret));
     println!("recovered: {}", hex::encode(&recovered_secret));
 
     println!("the secret was successfully reconstructed.");
@@ -15,7 +15,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Initialize secret buffer with random bytes
     let mut secret = [0u8; 32];
     thread_rng().fill_bytes(&mut secret);
-    println!("secret: {}\n", hex::encode(&secret));
+    println!("secret: {}\n", hex::encode(secret));
 
     // Split the secret into shares
     println!("# initial shares:");
@@ -0,0 +1,306 @@
+use crate::{
+    share::HEADER_LEN,
+    stream::{StreamCombine, StreamSplit, END_FRAME, FRAME_PREFIX_LEN, STREAM_CHUNK_LEN},
+};
+use rand::{rngs::StdRng, CryptoRng, RngCore, SeedableRng};
+use std::{future::poll_fn, io, pin::Pin, task::Poll};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use zeroize::{Zeroize, Zeroizing};
+
+/// Reads from the reader until the buffer is full or the end of the stream is reached.
+///
+/// ## Returns
+/// * The number of bytes read, which is only less than the length of the buffer at the end of the stream.
+async fn read_chunk(reader: &mut (impl AsyncRead + Unpin), buffer: &mut [u8]) -> io::Result<usize> {
+    let mut filled = 0;
+    while filled < buffer.len() {
+        match reader.read(&mut buffer[filled..]).await? {
+            0 => break,
+            read => filled += read,
+        }
+    }
+    Ok(filled)
+}
+
+/// Writes the buffer of every writer, given by `buffers`, to all writers concurrently.
+///
+/// A writer that is not ready does not block the others, therefore share streams consumed
+/// in lockstep, such as by [`combine_async`], make progress even through bounded pipes.
+async fn write_all_concurrently<'a, W: AsyncWrite + Unpin>(
+    writers: &mut [W],
+    buffers: impl Fn(usize) -> &'a [u8],
+) -> io::Result<()> {
+    let mut written = vec![0usize; writers.len()];
+    poll_fn(|cx| {
+        let mut pending = false;
+        for (index, (writer, written)) in writers.iter_mut().zip(written.iter_mut()).enumerate() {
+            let buffer = buffers(index);
+            while *written < buffer.len() {
+                match Pin::new(&mut *writer).poll_write(cx, &buffer[*written..]) {
+                    Poll::Ready(Ok(0)) => return Poll::Ready(Err(io::ErrorKind::WriteZero.into())),
+                    Poll::Ready(Ok(len)) => *written += len,
+                    Poll::Ready(Err(error)) => return Poll::Ready(Err(error)),
+                    Poll::Pending => {
+                        pending = true;
+                        break;
+                    }
+                }
+            }
+        }
+        if pending {
+            Poll::Pending
+        } else {
+            Poll::Ready(Ok(()))
+        }
+    })
+    .await
+}
+
+/// Splits a secret read from an asynchronous stream into multiple asynchronous share streams.
+///
+/// The share streams have the format of [`split_stream`](crate::split_stream) and are combined by
+/// either [`combine_async`] or [`combine_stream`](crate::combine_stream).
+///
+/// The frames of every chunk are written to all sinks concurrently, and the next chunk is only read once
+/// every sink accepted its frame, so the slowest sink applies backpressure to the reader. The chunks are
+/// sized so that the frames of all shares take at most 64 KiB, which bounds both the memory and the work
+/// between two awaits regardless of the number of shares.
+///
+/// The generator is seeded from the operating system, which keeps the future `Send`.
+///
+/// ## Arguments
+/// * `reader` - The stream of the secret to be split.
+/// * `writers` - The sinks receiving the share streams, one for each share.
+/// * `threshold` - Minimum number of shares required to reconstruct the secret.
+///
+/// ## Returns
+/// * The number of secret bytes read if successful; otherwise, an error.
+///
+/// ## Errors
+/// * Returns an error of kind `InvalidInput` if parameters are invalid (e.g., `parts` < `threshold`)
+///   or the secret is empty, wrapping the corresponding [`Error`](crate::Error).
+/// * Returns any error of the reader or the writers.
+pub async fn split_async<R, W>(reader: R, writers: &mut [W], threshold: usize) -> io::Result<u64>
+where
+    R: AsyncRead + Unpin,
+    W: AsyncWrite + Unpin,
+{
+    split_async_with_rng(reader, writers, threshold, &mut StdRng::from_entropy()).await
+}
+
+/// Splits a secret read from an asynchronous stream into multiple asynchronous share streams,
+/// drawing randomness from the provided generator.
+///
+/// See [`split_async`] for the format of the share streams and the backpressure.
+///
+/// ## Arguments
+/// * `reader` - The stream of the secret to be split.
+/// * `writers` - The sinks receiving the share streams, one for each share.
+/// * `threshold` - Minimum number of shares required to reconstruct the secret.
+/// * `rng` - A cryptographically secure random number generator.
+///
+/// ## Returns
+/// * The number of secret bytes read if successful; otherwise, an error.
+///
+/// ## Errors
+/// * Returns an error of kind `InvalidInput` if parameters are invalid (e.g., `parts` < `threshold`)
+///   or the secret is empty, wrapping the corresponding [`Error`](crate::Error).
+/// * Returns any error of the reader or the writers.
+pub async fn split_async_with_rng<R, W, G>(
+    mut reader: R,
+    writers: &mut [W],
+    threshold: usize,
+    rng: &mut G,
+) -> io::Result<u64>
+where
+    R: AsyncRead + Unpin,
+    W: AsyncWrite + Unpin,
+    G: RngCore + CryptoRng,
+{
+    // The chunk of the secret is zeroized when dropped, also when the future is cancelled.
+    let chunk_len = (STREAM_CHUNK_LEN / writers.len().max(1)).max(1);
+    let mut chunk = Zeroizing::new(vec![0u8; chunk_len]);
+
+    let mut len = read_chunk(&mut reader, &mut chunk).await?;
+    let mut split = StreamSplit::new(writers.len(), threshold, len, rng)?;
+
+    // The x-coordinate and the metadata of every share are written once, in the header of its stream.
+    let headers: Vec<_> = (0..writers.len()).map(|index| split.header(index)).collect();
+    write_all_concurrently(writers, |index| &headers[index]).await?;
+
+    // The frames of all shares suffice to reconstruct the chunk, therefore they are cleared after every write
+    // and zeroized when dropped, also when the future is cancelled.
+    let frame_len = FRAME_PREFIX_LEN + len;
+    let mut frames = Zeroizing::new(vec![0u8; writers.len() * frame_len]);
+    let mut total = 0u64;
+    while len > 0 {
+        split.load(&chunk[..len], rng);
+        let frame_len = FRAME_PREFIX_LEN + len;
+        for (index, frame) in frames.chunks_mut(frame_len).take(writers.len()).enumerate() {
+            frame.copy_from_slice(split.frame(index));
+        }
+        split.clear();
+
+        write_all_concurrently(writers, |index| &frames[index * frame_len..(index + 1) * frame_len]).await?;
+        frames[..].zeroize();
+        total += len as u64;
+        len = read_chunk(&mut reader, &mut chunk).await?;
+    }
+
+    // Terminate every share stream with a frame of zero length.
+    write_all_concurrently(writers, |_| &END_FRAME).await?;
+    for writer in writers.iter_mut() {
+        writer.flush().await?;
+    }
+    Ok(total)
+}
+
+/// Combines asynchronous share streams to reconstruct a secret into an asynchronous stream.
+///
+/// The share streams are read frame by frame, as written by [`split_async`] or
+/// [`split_stream`](crate::split_stream), and every chunk of the secret is written
+/// as soon as it was reconstructed, before the next frames are read.
+///
+/// ## Arguments
+/// * `readers` - The share streams of the secret.
+/// * `writer` - The sink receiving the secret.
+///
+/// ## Returns
+/// * The number of secret bytes written if successful; otherwise, an error.
+///
+/// ## Errors
+/// * Returns an error of kind `InvalidData` if the share streams are inconsistent or insufficient,
+///   wrapping the corresponding [`Error`](crate::Error), or if a frame is malformed.
+/// * Returns an error of kind `UnexpectedEof` if a share stream is truncated.
+/// * Returns any error of the readers or the writer.
+pub async fn combine_async<R, W>(readers: &mut [R], mut writer: W) -> io::Result<u64>
+where
+    R: AsyncRead + Unpin,
+    W: AsyncWrite + Unpin,
+{
+    let mut headers = vec![[0u8; HEADER_LEN]; readers.len()];
+    for (reader, header) in readers.iter_mut().zip(headers.iter_mut()) {
+        reader.read_exact(header).await?;
+    }
+    let mut combine = StreamCombine::new(&headers)?;
+
+    let mut total = 0u64;
+    loop {
+        // All share streams must carry frames of the same length.
+        let mut len = None;
+        for (index, reader) in readers.iter_mut().enumerate() {
+            let mut prefix = [0u8; FRAME_PREFIX_LEN];
+            reader.read_exact(&mut prefix).await?;
+            len = Some(StreamCombine::frame_len(prefix, index, len)?);
+        }
+        let len = len.unwrap_or(0);
+        if len == 0 {
+            writer.flush().await?;
+            return Ok(total);
+        }
+
+        // Reconstruct every byte of the chunk as the weighted sum of the y-coordinates, share by share.
+        combine.begin(len);
+        for (index, reader) in readers.iter_mut().enumerate() {
+            reader.read_exact(combine.payload()).await?;
+            combine.accumulate(index);
+        }
+
+        writer.write_all(combine.secret()).await?;
+        total += len as u64;
+    }
+}
+
+// Test cases for the asynchronous split and combine functions.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{combine_stream, split_stream, Error};
+
+    // The 'combine_async' function with share streams from the 'split_async' function.
+    #[tokio::test]
+    async fn it_combines_split_streams() {
+        let secret: Vec<u8> = (0..2 * STREAM_CHUNK_LEN + 17).map(|i| (i * 7) as u8).collect();
+
+        let mut streams = vec![Vec::new(); 5];
+        let read = split_async(&secret[..], &mut streams, 3).await.expect("split failed");
+        assert_eq!(read, secret.len() as u64);
+
+        let mut readers: Vec<&[u8]> = streams[1..4].iter().map(Vec::as_slice).collect();
+        let mut reconstructed = Vec::new();
+        let written = combine_async(&mut readers, &mut reconstructed)
+            .await
+            .expect("combine failed");
+
+        assert_eq!(written, secret.len() as u64);
+        assert_eq!(reconstructed, secret);
+    }
+
+    // The asynchronous and the synchronous share streams are interchangeable.
+    #[tokio::test]
+    async fn it_interoperates_with_sync_streams() {
+        let secret = b"test_secret";
+
+        let mut streams = vec![Vec::new(); 3];
+        split_async(&secret[..], &mut streams, 2).await.expect("split failed");
+        let mut reconstructed = Vec::new();
+        combine_stream(&mut [&streams[0][..], &streams[2][..]], &mut reconstructed).expect("combine failed");
+        assert_eq!(reconstructed, secret);
+
+        let mut streams = vec![Vec::new(); 3];
+        split_stream(&secret[..], &mut streams, 2).expect("split failed");
+        let mut reconstructed = Vec::new();
+        combine_async(&mut [&streams[1][..], &streams[2][..]], &mut reconstructed)
+            .await
+            .expect("combine failed");
+        assert_eq!(reconstructed, secret);
+    }
+
+    // The split waits for the sinks, which buffer less than a chunk, while the combine consumes the shares.
+    #[tokio::test]
+    async fn it_applies_backpressure() {
+        let secret: Vec<u8> = (0..3 * STREAM_CHUNK_LEN).map(|i| (i * 13) as u8).collect();
+
+        let (first_writer, first_reader) = tokio::io::duplex(1024);
+        let (second_writer, second_reader) = tokio::io::duplex(1024);
+        let mut writers = [first_writer, second_writer];
+        let mut readers = [first_reader, second_reader];
+
+        let mut reconstructed = Vec::new();
+        let (read, written) = tokio::join!(
+            split_async(&secret[..], &mut writers, 2),
+            combine_async(&mut readers, &mut reconstructed)
+        );
+
+        assert_eq!(read.expect("split failed"), secret.len() as u64);
+        assert_eq!(written.expect("combine failed"), secret.len() as u64);
+        assert_eq!(reconstructed, secret);
+    }
+
+    // The futures can be spawned onto a multi-threaded executor, and errors are reported as in `split_stream`.
+    #[tokio::test]
+    async fn it_reports_async_errors() {
+        fn assert_send<T: Send>(future: T) -> T {
+            future
+        }
+
+        let mut streams = vec![Vec::new(); 2];
+        let error = assert_send(split_async(&b""[..], &mut streams, 2))
+            .await
+            .expect_err("empty secret");
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(
+            error.get_ref().and_then(|error| error.downcast_ref::<Error>()),
+            Some(&Error::EmptySecret)
+        );
+
+        split_async(&b"test_secret"[..], &mut streams, 2)
+            .await
+            .expect("split failed");
+        let truncated = &streams[1][..streams[1].len() - 1];
+        let error = assert_send(combine_async(&mut [&streams[0][..], truncated], tokio::io::sink()))
+            .await
+            .expect_err("truncated");
+        assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof);
+    }
+}
@@ -0,0 +1,216 @@
+use crate::ops;
+
+/// An implementation of the slice operations of `GF(2^8)`, processing the elements in blocks.
+///
+/// Every backend is constant-time with respect to the elements of the slices.
+pub(crate) trait FieldBackend {
+    /// The number of elements processed at once.
+    const BLOCK_LEN: usize;
+
+    /// Computes `values[i] = values[i] * scalar + other[i]` if `HORNER` is set,
+    /// otherwise `values[i] = other[i] * scalar + values[i]`, for the longest prefix of whole blocks.
+    ///
+    /// ## Arguments
+    /// * `values` - The elements updated with the results.
+    /// * `other` - The elements combined with `values`, in the same length.
+    /// * `scalar` - The multiplier shared by all elements.
+    ///
+    /// ## Returns
+    /// * The number of elements processed, a multiple of `BLOCK_LEN`.
+    fn mult_xor<const HORNER: bool>(values: &mut [u8], other: &[u8], scalar: u8) -> usize;
+}
+
+/// Whether the target provides vector units, which the compiler utilizes to vectorize the portable backend.
+///
+/// The vectorized portable backend outperforms the bitsliced backend, which is therefore only used
+/// on targets without vector units. These are the Cortex-M targets (e.g. `thumbv6m-none-eabi` and
+/// `thumbv7em-none-eabi`), RISC-V targets without the vector extension (e.g. `riscv32imac-unknown-none-elf`),
+/// 32-bit x86 targets without SSE2 (e.g. `i586-unknown-linux-gnu`) and WebAssembly without `simd128`.
+/// Every `x86_64` and `aarch64` target provides vector units.
+///
+/// The `bitsliced` feature selects the bitsliced backend on every target, e.g. to test or benchmark it.
+pub(crate) const VECTORIZED: bool = cfg!(all(
+    not(feature = "bitsliced"),
+    any(
+        target_feature = "sse2",
+        target_feature = "neon",
+        target_feature = "simd128"
+    )
+));
+
+/// Computes the multiples `scalar * 2^i` of the scalar, which determine the product with any element
+/// as the `XOR` of the multiples selected by the bits of the element.
+fn multiples(scalar: u8) -> [u8; 8] {
+    core::array::from_fn(|i| ops::mult(scalar, 1 << i))
+}
+
+/// The portable backend, processing one element at a time.
+pub(crate) struct Portable;
+
+impl FieldBackend for Portable {
+    const BLOCK_LEN: usize = 1;
+
+    // The multiples are selected with masks instead of branches or table lookups indexed by secret values,
+    // which keeps the execution time independent of the elements and allows the compiler to vectorize the loop.
+    fn mult_xor<const HORNER: bool>(values: &mut [u8], other: &[u8], scalar: u8) -> usize {
+        let multiples = multiples(scalar);
+
+        let mut processed = 0;
+        for (value, &other) in values.iter_mut().zip(other.iter()) {
+            let (factor, addend) = if HORNER { (*value, other) } else { (other, *value) };
+            let mut product = 0u8;
+            for (i, &multiple) in multiples.iter().enumerate() {
+                // A mask of all ones if the i-th bit of the factor is set, otherwise zero.
+                let mask = 0u8.wrapping_sub((factor >> i) & 1);
+                product ^= multiple & mask;
+            }
+            *value = product ^ addend;
+            processed += 1;
+        }
+        processed
+    }
+}
+
+/// The bitsliced backend, processing 64 elements at a time using only `AND`, `XOR` and shifts on `u64` words.
+///
+/// A block of 64 elements is transposed into 8 bit-planes, where the j-th bit of the i-th plane holds the
+/// i-th bit of the j-th element. Multiplication by a scalar is linear over `GF(2)`, therefore every plane of
+/// the product is the `XOR` of the planes of the factor selected by the bits of the multiples of the scalar.
+pub(crate) struct Bitsliced;
+
+impl Bitsliced {
+    /// Transposes the 8x8 bit matrix of every word, with the bytes as rows,
+    /// and then the 8x8 byte matrix formed by the words.
+    ///
+    /// Both transpositions are involutions, therefore the planes are transposed back
+    /// by applying them in the reverse order.
+    fn transpose_bits(words: &mut [u64; 8]) {
+        for word in words.iter_mut() {
+            let mut x = *word;
+            let t = (x ^ (x >> 7)) & 0x00AA_00AA_00AA_00AA;
+            x ^= t ^ (t << 7);
+            let t = (x ^ (x >> 14)) & 0x0000_CCCC_0000_CCCC;
+            x ^= t ^ (t << 14);
+            let t = (x ^ (x >> 28)) & 0x0000_0000_F0F0_F0F0;
+            x ^= t ^ (t << 28);
+            *word = x;
+        }
+    }
+
+    /// Transposes the 8x8 byte matrix formed by the words, by swapping blocks of 4, 2 and 1 bytes.
+    fn transpose_bytes(words: &mut [u64; 8]) {
+        for (shift, mask) in [
+            (32, 0x0000_0000_FFFF_FFFFu64),
+            (16, 0x0000_FFFF_0000_FFFF),
+            (8, 0x00FF_00FF_00FF_00FF),
+        ] {
+            let distance = shift / 8;
+            for j in (0..8).filter(|j| j & distance == 0) {
+                let (a, b) = (words[j], words[j + distance]);
+                words[j] = (a & mask) | ((b & mask) << shift);
+                words[j + distance] = ((a >> shift) & mask) | (b & !mask);
+            }
+        }
+    }
+}
+
+impl FieldBackend for Bitsliced {
+    const BLOCK_LEN: usize = 64;
+
+    fn mult_xor<const HORNER: bool>(values: &mut [u8], other: &[u8], scalar: u8) -> usize {
+        let multiples = multiples(scalar);
+
+        // The k-th plane of the product includes the i-th plane of the factor
+        // if the k-th bit of the i-th multiple is set, selected with masks.
+        let mut masks = [[0u64; 8]; 8];
+        for (masks, &multiple) in masks.iter_mut().zip(multiples.iter()) {
+            for (k, mask) in masks.iter_mut().enumerate() {
+                *mask = 0u64.wrapping_sub(((multiple >> k) & 1) as u64);
+            }
+        }
+
+        let mut processed = 0;
+        for (values, other) in values
+            .chunks_exact_mut(Self::BLOCK_LEN)
+            .zip(other.chunks_exact(Self::BLOCK_LEN))
+        {
+            let factor: &[u8] = if HORNER { values } else { other };
+            let mut planes: [u64; 8] =
+                core::array::from_fn(|j| u64::from_le_bytes(factor[8 * j..8 * j + 8].try_into().expect("8 bytes")));
+            Bitsliced::transpose_bits(&mut planes);
+            Bitsliced::transpose_bytes(&mut planes);
+
+            let mut product = [0u64; 8];
+            for (plane, masks) in planes.iter().zip(masks.iter()) {
+                for (result, &mask) in product.iter_mut().zip(masks.iter()) {
+                    *result ^= plane & mask;
+                }
+            }
+
+            Bitsliced::transpose_bytes(&mut product);
+            Bitsliced::transpose_bits(&mut product);
+
+            // The addition is performed on the bytes, since the transposition is linear.
+            for (j, word) in product.iter().enumerate() {
+                let addend: &[u8] = if HORNER {
+                    &other[8 * j..8 * j + 8]
+                } else {
+                    &values[8 * j..8 * j + 8]
+                };
+                let result = word ^ u64::from_le_bytes(addend.try_into().expect("8 bytes"));
+                values[8 * j..8 * j + 8].copy_from_slice(&result.to_le_bytes());
+            }
+            processed += Self::BLOCK_LEN;
+        }
+        processed
+    }
+}
+
+// Test cases for the field backends.
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The transposition into bit-planes places the i-th bit of the j-th element into the j-th bit of the i-th plane.
+    #[test]
+    fn it_transposes_into_planes() {
+        let bytes: [u8; 64] = core::array::from_fn(|j| (j as u8).wrapping_mul(37) ^ 0xC5);
+        let mut planes: [u64; 8] =
+            core::array::from_fn(|j| u64::from_le_bytes(bytes[8 * j..8 * j + 8].try_into().expect("8 bytes")));
+        Bitsliced::transpose_bits(&mut planes);
+        Bitsliced::transpose_bytes(&mut planes);
+
+        for (i, plane) in planes.iter().enumerate() {
+            for (j, byte) in bytes.iter().enumerate() {
+                assert_eq!((plane >> j) & 1, ((byte >> i) & 1) as u64);
+            }
+        }
+    }
+
+    // Both backends match the scalar multiplication, leaving the elements after the last block untouched.
+    #[test]
+    fn it_matches_scalar_multiplication() {
+        fn check<B: FieldBackend>() {
+            let values: [u8; 2 * 64 + 9] = core::array::from_fn(|i| (i as u8).wrapping_mul(13));
+            let other: [u8; 2 * 64 + 9] = core::array::from_fn(|i| (i as u8).wrapping_mul(31) ^ 0x5A);
+
+            for scalar in 0..=255 {
+                let mut horner = values;
+                let processed = B::mult_xor::<true>(&mut horner, &other, scalar);
+                let mut accumulated = values;
+                assert_eq!(B::mult_xor::<false>(&mut accumulated, &other, scalar), processed);
+                assert_eq!(processed, values.len() - values.len() % B::BLOCK_LEN);
+
+                for i in 0..processed {
+                    assert_eq!(horner[i], ops::mult(values[i], scalar) ^ other[i]);
+                    assert_eq!(accumulated[i], ops::mult(other[i], scalar) ^ values[i]);
+                }
+                assert_eq!(horner[processed..], values[processed..]);
+                assert_eq!(accumulated[processed..], values[processed..]);
+            }
+        }
+
+        check::<Portable>();
+        check::<Bitsliced>();
+    }
+}
@@ -0,0 +1,345 @@
+use crate::{polynomial, reconstruct, shuffled_x_coordinates, validate_parameters, Error, Header, Share};
+#[cfg(feature = "refresh")]
+use crate::{refresh_preserving, validate_threshold};
+use alloc::{vec, vec::Vec};
+use hmac::{Hmac, Mac};
+use rand::{CryptoRng, Rng, RngCore};
+use sha2::Sha256;
+use subtle::ConstantTimeEq;
+use zeroize::Zeroize;
+
+/// The x-coordinate reserved for the digest share, which is never handed out as a share.
+pub(crate) const DIGEST_X: u8 = 255;
+
+/// The number of bytes of the digest embedded in the digest share.
+const DIGEST_LENGTH: usize = 4;
+
+/// The minimum length of a secret that can be split in checked mode.
+const MIN_SECRET_LENGTH: usize = 16;
+
+/// The maximum number of shares, excluding the reserved x-coordinate of the digest share.
+const MAX_CHECKED_PARTS: usize = DIGEST_X as usize - 1;
+
+/// Computes the digest of the secret, keyed with the random part of the digest share.
+///
+/// ## Arguments
+/// * `key` - The random part of the digest share.
+/// * `secret` - The secret to be authenticated.
+///
+/// ## Returns
+/// * The first `DIGEST_LENGTH` bytes of `HMAC-SHA256(key, secret)`.
+fn digest(key: &[u8], secret: &[u8]) -> [u8; DIGEST_LENGTH] {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(secret);
+
+    let mut digest = [0u8; DIGEST_LENGTH];
+    digest.copy_from_slice(&mac.finalize().into_bytes()[..DIGEST_LENGTH]);
+    digest
+}
+
+/// Splits a secret into multiple shares that allow the reconstruction to be verified.
+///
+/// Modelled on the digest share of SLIP-39, the polynomial of every byte additionally passes through
+/// a digest share at the reserved x-coordinate `255`. The digest share consists of `HMAC-SHA256(r, secret)`,
+/// truncated to 4 bytes, followed by the random bytes `r`, and is reconstructed alongside the secret
+/// by [`combine_checked`]. The shares remain regular shares and can also be combined with
+/// [`combine_shares`](crate::combine_shares), without verification.
+///
+/// The shares are marked as checked in the binary format of [`CHECKED_FORMAT_VERSION`](crate::CHECKED_FORMAT_VERSION),
+/// and must be refreshed with `refresh_checked`, which preserves the digest share. Other refreshes reject them,
+/// unless they were converted into the legacy byte layout, after which the digest share changes and
+/// [`combine_checked`] fails.
+///
+/// ## Arguments
+/// * `secret` - The secret to be split, at least 16 bytes long.
+/// * `parts` - Total number of shares to create, at most 254.
+/// * `threshold` - Minimum number of shares required to reconstruct the secret.
+///
+/// ## Returns
+/// * A vector of shares if successful; otherwise, an error.
+///
+/// ## Errors
+/// * Returns an error if parameters are invalid (e.g., `parts` < `threshold`) or the secret is too short.
+#[cfg(feature = "std")]
+pub fn split_checked(secret: &[u8], parts: usize, threshold: usize) -> Result<Vec<Share>, Error> {
+    split_checked_with_rng(secret, parts, threshold, &mut rand::thread_rng())
+}
+
+/// Splits a secret into multiple shares that allow the reconstruction to be verified,
+/// drawing randomness from the provided generator.
+///
+/// See [`split_checked`] for a description of the checked mode.
+///
+/// ## Arguments
+/// * `secret` - The secret to be split, at least 16 bytes long.
+/// * `parts` - Total number of shares to create, at most 254.
+/// * `threshold` - Minimum number of shares required to reconstruct the secret.
+/// * `rng` - A cryptographically secure random number generator.
+///
+/// ## Returns
+/// * A vector of shares if successful; otherwise, an error.
+///
+/// ## Errors
+/// * Returns an error if parameters are invalid (e.g., `parts` < `threshold`) or the secret is too short.
+pub fn split_checked_with_rng<R: RngCore + CryptoRng>(
+    secret: &[u8],
+    parts: usize,
+    threshold: usize,
+    rng: &mut R,
+) -> Result<Vec<Share>, Error> {
+    // Validate the input parameters.
+    validate_parameters(parts, threshold, MAX_CHECKED_PARTS)?;
+    if secret.len() < MIN_SECRET_LENGTH {
+        return Err(Error::SecretTooShort {
+            len: secret.len(),
+            min: MIN_SECRET_LENGTH,
+        });
+    }
+
+    let x_coordinates = shuffled_x_coordinates(MAX_CHECKED_PARTS as u8, rng);
+    let header = Header::new_checked(threshold as u8, rng.gen());
+
+    // Construct the digest share from the digest of the secret followed by random bytes.
+    let mut digest_share = vec![0u8; secret.len()];
+    rng.fill(&mut digest_share[DIGEST_LENGTH..]);
+    let digest = digest(&digest_share[DIGEST_LENGTH..], secret);
+    digest_share[..DIGEST_LENGTH].copy_from_slice(&digest);
+
+    // The polynomial of every byte is determined by `threshold` points: the secret at `x = 0`,
+    // the digest share at `x = 255` and the random y-coordinates of the first `threshold - 2` shares.
+    let random_shares = threshold - 2;
+    let mut shares: Vec<_> = x_coordinates[..parts]
+        .iter()
+        .enumerate()
+        .map(|(idx, &x)| {
+            let mut y = vec![0u8; secret.len()];
+            if idx < random_shares {
+                rng.fill(y.as_mut_slice());
+            }
+            Share::new(x, y, Some(header))
+        })
+        .collect();
+
+    let mut x_samples = vec![0, DIGEST_X];
+    x_samples.extend_from_slice(&x_coordinates[..random_shares]);
+    let mut y_samples = vec![0u8; threshold];
+
+    // The Lagrange weights at the x-coordinate of every remaining share are computed once for all bytes.
+    let mut weights = vec![0u8; (parts - random_shares) * threshold];
+    for (share_weights, share) in weights.chunks_mut(threshold).zip(shares[random_shares..].iter()) {
+        polynomial::lagrange_weights(&x_samples, share.x(), share_weights);
+    }
+
+    // Evaluate the remaining shares by interpolating the points determining each polynomial.
+    for (idx, &secret_byte) in secret.iter().enumerate() {
+        y_samples[0] = secret_byte;
+        y_samples[1] = digest_share[idx];
+        for (sample, share) in y_samples[2..].iter_mut().zip(shares.iter()) {
+            *sample = share.y()[idx];
+        }
+
+        for (share_weights, share) in weights.chunks(threshold).zip(shares[random_shares..].iter_mut()) {
+            share.y_mut()[idx] = polynomial::weighted_sum(share_weights, &y_samples);
+        }
+    }
+
+    // Clear memory associated with the secret-dependent intermediate values.
+    digest_share.zeroize();
+    y_samples.zeroize();
+
+    Ok(shares)
+}
+
+/// Combines shares produced by [`split_checked`] and verifies the reconstructed secret.
+///
+/// ## Arguments
+/// * `shares` - Shares of the secret.
+///
+/// ## Returns
+/// * The original secret if successful; otherwise, an error.
+///
+/// ## Errors
+/// * Returns an error if shares are inconsistent or insufficient.
+/// * Returns [`Error::IntegrityCheckFailed`] if the shares do not reconstruct the original secret,
+///   e.g., when they are corrupted, stale or originate from different splits.
+pub fn combine_checked(shares: &[Share]) -> Result<Vec<u8>, Error> {
+    let mut secret = reconstruct(shares, 0)?;
+    let mut digest_share = reconstruct(shares, DIGEST_X)?;
+
+    // Compare the embedded digest with the digest of the reconstructed secret in constant-time.
+    let valid = secret.len() >= MIN_SECRET_LENGTH
+        && bool::from(digest(&digest_share[DIGEST_LENGTH..], &secret).ct_eq(&digest_share[..DIGEST_LENGTH]));
+    digest_share.zeroize();
+
+    if !valid {
+        // The reconstructed bytes are not the original secret, but are cleared nonetheless.
+        secret.zeroize();
+        return Err(Error::IntegrityCheckFailed);
+    }
+
+    Ok(secret)
+}
+
+/// Generates update keys and refreshes the shares produced by [`split_checked`],
+/// preserving the digest share.
+///
+/// ## Arguments
+/// * `shares` - Current shares to be refreshed
+/// * `threshold` - Minimum number of shares required to reconstruct the secret, at least 3.
+///
+/// ## Returns
+/// * Updated shares if successful; otherwise, an error.
+///
+/// ## Errors
+/// * Returns an error if shares are inconsistent or invalid.
+/// * Returns [`Error::RefreshThresholdTooSmall`] if the threshold is `2`, since the polynomials
+///   are then determined by the secret and the digest share alone.
+#[cfg(all(feature = "refresh", feature = "std"))]
+pub fn refresh_checked(shares: &[Share], threshold: usize) -> Result<Vec<Share>, Error> {
+    refresh_checked_with_rng(shares, threshold, &mut rand::thread_rng())
+}
+
+/// Generates update keys and refreshes the shares produced by [`split_checked_with_rng`],
+/// preserving the digest share and drawing randomness from the provided generator.
+///
+/// The refresh polynomials vanish at both `x = 0` and the x-coordinate of the digest share,
+/// therefore the refreshed shares still pass [`combine_checked`].
+///
+/// ## Arguments
+/// * `shares` - Current shares to be refreshed
+/// * `threshold` - Minimum number of shares required to reconstruct the secret, at least 3.
+/// * `rng` - A cryptographically secure random number generator.
+///
+/// ## Returns
+/// * Updated shares if successful; otherwise, an error.
+///
+/// ## Errors
+/// * Returns an error if shares are inconsistent or invalid.
+/// * Returns [`Error::RefreshThresholdTooSmall`] if the threshold is `2`, since the polynomials
+///   are then determined by the secret and the digest share alone.
+#[cfg(feature = "refresh")]
+pub fn refresh_checked_with_rng<R: RngCore + CryptoRng>(
+    shares: &[Share],
+    threshold: usize,
+    rng: &mut R,
+) -> Result<Vec<Share>, Error> {
+    // Validate inputs
+    validate_parameters(shares.len(), threshold, MAX_CHECKED_PARTS)?;
+    if threshold < 3 {
+        return Err(Error::RefreshThresholdTooSmall { threshold, min: 3 });
+    }
+    validate_threshold(shares, threshold)?;
+
+    Ok(refresh_preserving(shares, threshold, &[DIGEST_X], rng))
+}
+
+// Test cases for the checked split mode.
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::combine_shares;
+
+    // The 'combine_checked' function with shares from the 'split_checked' function.
+    #[test]
+    fn it_combines_checked_shares() {
+        let secret = b"a_sixteen_byte_secret";
+
+        for threshold in 2..=5 {
+            let shares = split_checked(secret, 5, threshold).expect("split failed");
+            assert!(shares.iter().all(|share| share.x() != DIGEST_X));
+
+            let reconstructed = combine_checked(&shares[5 - threshold..]).expect("combine failed");
+            assert_eq!(reconstructed, secret);
+
+            // The shares are regular shares and can be combined without verification.
+            let reconstructed = combine_shares(&shares[..threshold]).expect("combine failed");
+            assert_eq!(reconstructed, secret);
+        }
+    }
+
+    // The 'combine_checked' function detects corrupted shares.
+    #[test]
+    fn it_fails_to_combine_corrupted_shares() {
+        let secret = b"a_sixteen_byte_secret";
+        let shares = split_checked(secret, 5, 3).expect("split failed");
+
+        let mut corrupted = shares[1].clone();
+        corrupted.y_mut()[4] ^= 0x01;
+        let selected = vec![shares[0].clone(), corrupted, shares[2].clone()];
+
+        // Without verification, the corrupted share results in an incorrect secret.
+        assert_ne!(combine_shares(&selected).expect("combine failed"), secret);
+        assert_eq!(combine_checked(&selected), Err(Error::IntegrityCheckFailed));
+    }
+
+    // The 'combine_checked' function detects shares that were not split in checked mode.
+    #[test]
+    fn it_fails_to_combine_unchecked_shares() {
+        let shares = crate::split_shares(b"a_sixteen_byte_secret", 3, 2).expect("split failed");
+        assert_eq!(combine_checked(&shares), Err(Error::IntegrityCheckFailed));
+    }
+
+    // The 'split_checked' function reports invalid parameters.
+    #[test]
+    fn it_fails_to_split_checked_invalid_input() {
+        assert_eq!(
+            split_checked(b"too_short", 5, 3),
+            Err(Error::SecretTooShort {
+                len: 9,
+                min: MIN_SECRET_LENGTH
+            })
+        );
+        assert_eq!(
+            split_checked(b"a_sixteen_byte_secret", 255, 3),
+            Err(Error::PartsExceedField { parts: 255, max: 254 })
+        );
+    }
+
+    // The 'split_checked_with_rng' function is reproducible with a seeded generator.
+    #[test]
+    fn it_splits_checked_with_seeded_rng() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let secret = b"a_sixteen_byte_secret";
+        let first = split_checked_with_rng(secret, 5, 3, &mut StdRng::seed_from_u64(42)).expect("split failed");
+        let second = split_checked_with_rng(secret, 5, 3, &mut StdRng::seed_from_u64(42)).expect("split failed");
+
+        assert_eq!(first, second);
+        assert_eq!(combine_checked(&first[1..4]).expect("combine failed"), secret);
+    }
+
+    // The 'refresh_checked' function preserves the digest share, unlike the 'refresh_shares' function.
+    #[cfg(feature = "refresh")]
+    #[test]
+    fn it_refreshes_checked_shares() {
+        let secret = b"a_sixteen_byte_secret";
+        let shares = split_checked(secret, 5, 3).expect("split failed");
+
+        let refreshed = refresh_checked(&shares, 3).expect("refresh failed");
+        assert_ne!(refreshed, shares);
+        assert_eq!(combine_checked(&refreshed[2..]).expect("combine failed"), secret);
+        assert_eq!(combine_shares(&refreshed[..3]).expect("combine failed"), secret);
+
+        assert!(refreshed.iter().all(Share::is_checked));
+
+        // A regular refresh rejects checked shares, since it would change the digest share.
+        assert_eq!(crate::refresh_shares(&shares, 3), Err(Error::CheckedRefreshRequired));
+
+        // Without the metadata of the legacy byte layout, a regular refresh keeps the secret but changes the digest share.
+        let legacy: Vec<Vec<u8>> = shares.iter().cloned().map(Vec::from).collect();
+        let refreshed: Vec<Share> = crate::refresh(&legacy, 3)
+            .expect("refresh failed")
+            .iter()
+            .map(|share| Share::try_from(share.as_slice()).expect("parse failed"))
+            .collect();
+        assert_eq!(combine_shares(&refreshed[..3]).expect("combine failed"), secret);
+        assert_eq!(combine_checked(&refreshed[..3]), Err(Error::IntegrityCheckFailed));
+
+        // With a threshold of two, the polynomials cannot be refreshed without changing the digest share.
+        let shares = split_checked(secret, 5, 2).expect("split failed");
+        assert_eq!(
+            refresh_checked(&shares, 2),
+            Err(Error::RefreshThresholdTooSmall { threshold: 2, min: 3 })
+        );
+    }
+}
@@ -0,0 +1,256 @@
+use crate::{polynomial, validate_threshold, x_samples, Error, Share};
+use alloc::{vec, vec::Vec};
+use zeroize::Zeroize;
+
+/// The maximum number of reference sets examined before the search for a consistent majority is abandoned.
+const MAX_REFERENCE_SETS: usize = 10_000;
+
+/// The outcome of a consistency check of the shares.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConsistencyReport {
+    /// The indices of the shares that do not lie on the common polynomials, in ascending order.
+    inconsistent: Vec<usize>,
+    /// Whether the consistent shares form a majority large enough to uniquely identify the inconsistent ones.
+    conclusive: bool,
+}
+
+impl ConsistencyReport {
+    /// Returns `true` if all shares lie on the same polynomials.
+    pub fn is_consistent(&self) -> bool {
+        self.inconsistent.is_empty()
+    }
+
+    /// Returns the indices of the inconsistent shares within the input, in ascending order.
+    pub fn inconsistent(&self) -> &[usize] {
+        &self.inconsistent
+    }
+
+    /// Returns `true` if the consistent shares uniquely determine the polynomials,
+    /// in which case the inconsistent shares are identified with certainty.
+    ///
+    /// With exactly `threshold` shares there is no redundancy and the report is never conclusive.
+    pub fn is_conclusive(&self) -> bool {
+        self.conclusive
+    }
+}
+
+/// Verifies that the shares lie on the same polynomials of degree `threshold - 1`,
+/// without reconstructing the secret.
+///
+/// The polynomials determined by a reference set of `threshold` shares are interpolated at the
+/// x-coordinate of every other share and compared with its y-coordinates. The polynomials are never
+/// evaluated at `x = 0`, therefore the secret is not materialized.
+///
+/// If some shares are inconsistent, further reference sets are examined until the agreeing shares form
+/// a majority of more than `(n + threshold - 1) / 2` shares, which no other polynomials can achieve.
+/// The remaining shares are then identified as inconsistent. Otherwise, the report lists the shares
+/// outside of the largest agreeing set found, and is marked as inconclusive.
+///
+/// ## Arguments
+/// * `shares` - Shares of the secret.
+/// * `threshold` - Minimum number of shares required to reconstruct the secret.
+///
+/// ## Returns
+/// * A report of the inconsistent shares if successful; otherwise, an error.
+///
+/// ## Errors
+/// * Returns an error if shares are inconsistent in length or metadata, or insufficient.
+/// * Returns [`Error::ZeroXCoordinate`] if a share has the x-coordinate zero.
+pub fn verify_consistency(shares: &[Share], threshold: usize) -> Result<ConsistencyReport, Error> {
+    validate_threshold(shares, threshold)?;
+    let x_samples = x_samples(shares)?;
+    // A share at `x = 0` would require the reference polynomials to be interpolated at the secret.
+    if let Some(index) = x_samples.iter().position(|&x| x == 0) {
+        return Err(Error::ZeroXCoordinate { index });
+    }
+
+    let parts = shares.len();
+    let majority = (parts + threshold - 1) / 2;
+
+    let mut best: Option<Vec<bool>> = None;
+    let mut best_count = 0;
+
+    // Examine the cyclic windows of consecutive shares first, followed by all remaining combinations.
+    let windows = (0..parts).map(|i| (0..threshold).map(|k| (i + k) % parts).collect::<Vec<_>>());
+    let reference_sets = windows
+        .chain(Combinations::new(parts, threshold))
+        .take(MAX_REFERENCE_SETS);
+
+    for reference in reference_sets {
+        let mut agreeing = vec![false; parts];
+        for &i in reference.iter() {
+            agreeing[i] = true;
+        }
+        for (j, agrees) in agreeing.iter_mut().enumerate() {
+            if !*agrees {
+                *agrees = lies_on(shares, &x_samples, &reference, j);
+            }
+        }
+
+        let count = agreeing.iter().filter(|&&a| a).count();
+        if parts > threshold && count > majority {
+            return Ok(report(&agreeing, true));
+        }
+        if count > best_count {
+            best_count = count;
+            best = Some(agreeing);
+        }
+    }
+
+    let agreeing = best.expect("at least one reference set");
+    Ok(report(&agreeing, false))
+}
+
+/// Checks whether the share at `index` lies on the polynomials determined by the `reference` shares.
+fn lies_on(shares: &[Share], x_samples: &[u8], reference: &[usize], index: usize) -> bool {
+    let x_reference: Vec<u8> = reference.iter().map(|&i| x_samples[i]).collect();
+    let mut y_reference = vec![0u8; reference.len()];
+
+    // The interpolation point is the x-coordinate of a share, which `verify_consistency` ensures is non-zero.
+    let mut weights = vec![0u8; reference.len()];
+    polynomial::lagrange_weights(&x_reference, x_samples[index], &mut weights);
+
+    let mut consistent = true;
+    for (idx, &y) in shares[index].y().iter().enumerate() {
+        for (sample, &i) in y_reference.iter_mut().zip(reference.iter()) {
+            *sample = shares[i].y()[idx];
+        }
+        if polynomial::weighted_sum(&weights, &y_reference) != y {
+            consistent = false;
+            break;
+        }
+    }
+
+    y_reference.zeroize();
+    consistent
+}
+
+/// Builds a report from the agreement of each share with the reference polynomials.
+fn report(agreeing: &[bool], conclusive: bool) -> ConsistencyReport {
+    let inconsistent = agreeing
+        .iter()
+        .enumerate()
+        .filter(|(_, &a)| !a)
+        .map(|(i, _)| i)
+        .collect();
+    ConsistencyReport {
+        inconsistent,
+        conclusive,
+    }
+}
+
+/// An iterator over the `k`-element combinations of `0..n` in lexicographic order.
+struct Combinations {
+    indices: Vec<usize>,
+    n: usize,
+    done: bool,
+}
+
+impl Combinations {
+    fn new(n: usize, k: usize) -> Combinations {
+        Combinations {
+            indices: (0..k).collect(),
+            n,
+            done: k > n,
+        }
+    }
+}
+
+impl Iterator for Combinations {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Vec<usize>> {
+        if self.done {
+            return None;
+        }
+        let current = self.indices.clone();
+
+        // Advance the rightmost index that has not yet reached its final position.
+        let k = self.indices.len();
+        match (0..k).rev().find(|&i| self.indices[i] != i + self.n - k) {
+            Some(i) => {
+                self.indices[i] += 1;
+                for j in (i + 1)..k {
+                    self.indices[j] = self.indices[j - 1] + 1;
+                }
+            }
+            None => self.done = true,
+        }
+
+        Some(current)
+    }
+}
+
+// Test cases for the consistency check.
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::split_shares;
+
+    // Shares from the same split are consistent.
+    #[test]
+    fn it_verifies_consistent_shares() {
+        let shares = split_shares(b"test_secret", 5, 3).expect("split failed");
+
+        let report = verify_consistency(&shares, 3).expect("verification failed");
+        assert!(report.is_consistent());
+        assert!(report.is_conclusive());
+
+        // Without redundancy, the shares are trivially consistent.
+        let report = verify_consistency(&shares[..3], 3).expect("verification failed");
+        assert!(report.is_consistent());
+        assert!(!report.is_conclusive());
+    }
+
+    // Inconsistent shares are identified, even within the first reference set.
+    #[test]
+    fn it_identifies_inconsistent_shares() {
+        let mut shares = split_shares(b"test_secret", 7, 3).expect("split failed");
+        shares[0].y_mut()[2] ^= 0x10;
+        shares[4].y_mut()[10] ^= 0x01;
+
+        let report = verify_consistency(&shares, 3).expect("verification failed");
+        assert!(!report.is_consistent());
+        assert!(report.is_conclusive());
+        assert_eq!(report.inconsistent(), &[0, 4]);
+    }
+
+    // Without a sufficient majority, the inconsistent shares cannot be identified with certainty.
+    #[test]
+    fn it_reports_inconclusive_results() {
+        let mut shares = split_shares(b"test_secret", 4, 3).expect("split failed");
+        shares[1].y_mut()[0] ^= 0x01;
+
+        let report = verify_consistency(&shares, 3).expect("verification failed");
+        assert!(!report.is_consistent());
+        assert!(!report.is_conclusive());
+        assert_eq!(report.inconsistent().len(), 1);
+    }
+
+    // Shares at `x = 0`, such as malformed legacy shares, are rejected before any interpolation.
+    #[test]
+    fn it_fails_with_zero_x_coordinate() {
+        let mut legacy = crate::split(b"test_secret", 5, 3).expect("split failed");
+        *legacy[3].last_mut().expect("empty share") = 0;
+        let shares: Vec<Share> = legacy
+            .iter()
+            .map(|share| Share::try_from(share.as_slice()).expect("parse failed"))
+            .collect();
+
+        assert_eq!(
+            verify_consistency(&shares, 3).unwrap_err(),
+            Error::ZeroXCoordinate { index: 3 }
+        );
+    }
+
+    // The combinations are enumerated in lexicographic order.
+    #[test]
+    fn it_enumerates_combinations() {
+        let combinations: Vec<_> = Combinations::new(4, 2).collect();
+        assert_eq!(
+            combinations,
+            vec![vec![0, 1], vec![0, 2], vec![0, 3], vec![1, 2], vec![1, 3], vec![2, 3]]
+        );
+        assert_eq!(Combinations::new(2, 3).count(), 0);
+    }
+}
@@ -0,0 +1,627 @@
+use crate::{field::Field, polynomial::Polynomial, unique_wide_x_coordinates, validate_parameters, Error};
+use alloc::vec::Vec;
+use core::fmt;
+use curve25519_dalek::{
+    constants::RISTRETTO_BASEPOINT_POINT,
+    ristretto::{CompressedRistretto, RistrettoPoint},
+    Scalar,
+};
+#[cfg(feature = "secp256k1")]
+use k256::elliptic_curve::group::GroupEncoding;
+use rand::{CryptoRng, Rng, RngCore};
+use sha2::{Digest, Sha256};
+use subtle::{ConstantTimeEq, CtOption};
+use zeroize::Zeroize;
+
+/// The maximum number of scalar shares, bounded by the 2-byte x-coordinates of the binary format.
+pub const MAX_SCALAR_PARTS: usize = u16::MAX as usize;
+
+/// The length of the header in the binary format of scalar shares: version (1 byte),
+/// threshold (2 bytes), split identifier (4 bytes) and x-coordinate (2 bytes).
+pub(crate) const SCALAR_HEADER_LEN: usize = 9;
+
+/// The scalar field of a prime-order group, whose elements have a canonical encoding.
+///
+/// Shares of a scalar are themselves scalars, which allows them to be used as the signing shares
+/// of threshold signature schemes over the same group.
+pub trait ScalarField: Field {
+    /// The version of the binary format of [`ScalarShare::to_bytes`], identifying the group.
+    const FORMAT_VERSION: u8;
+
+    /// The length of the canonical encoding of a scalar.
+    const ENCODED_LEN: usize;
+
+    /// Writes the canonical encoding of the scalar into `bytes`, of [`ScalarField::ENCODED_LEN`] bytes.
+    fn write_canonical(&self, bytes: &mut [u8]);
+
+    /// Parses a scalar from its canonical encoding, of [`ScalarField::ENCODED_LEN`] bytes.
+    ///
+    /// ## Returns
+    /// * The scalar, or none if the encoding is not canonical.
+    fn from_canonical(bytes: &[u8]) -> CtOption<Self>;
+}
+
+// The scalars of Ristretto255 and Ed25519, modulo the prime order `2^252 + 27742317777372353535851937790883648493`.
+impl Field for Scalar {
+    fn zero() -> Self {
+        Scalar::ZERO
+    }
+
+    fn one() -> Self {
+        Scalar::ONE
+    }
+
+    fn add(self, other: Self) -> Self {
+        self + other
+    }
+
+    fn sub(self, other: Self) -> Self {
+        self - other
+    }
+
+    fn mul(self, other: Self) -> Self {
+        self * other
+    }
+
+    fn inv(self) -> CtOption<Self> {
+        // The inversion is constant-time and results to zero for zero, which is not invertible.
+        CtOption::new(Scalar::invert(&self), !self.ct_eq(&Scalar::ZERO))
+    }
+
+    fn random<R: RngCore + CryptoRng>(rng: &mut R) -> Self {
+        Scalar::random(rng)
+    }
+}
+
+// Scalars are encoded in 32 little-endian bytes, as in Ed25519 and Ristretto255.
+impl ScalarField for Scalar {
+    const FORMAT_VERSION: u8 = 0x83;
+    const ENCODED_LEN: usize = 32;
+
+    fn write_canonical(&self, bytes: &mut [u8]) {
+        bytes.copy_from_slice(self.as_bytes());
+    }
+
+    fn from_canonical(bytes: &[u8]) -> CtOption<Self> {
+        let mut encoding = [0u8; 32];
+        encoding.copy_from_slice(bytes);
+        let scalar = Scalar::from_canonical_bytes(encoding);
+        encoding.zeroize();
+        scalar
+    }
+}
+
+// The scalars of secp256k1, modulo the prime order of its group.
+#[cfg(feature = "secp256k1")]
+impl Field for k256::Scalar {
+    fn zero() -> Self {
+        k256::Scalar::ZERO
+    }
+
+    fn one() -> Self {
+        k256::Scalar::ONE
+    }
+
+    fn add(self, other: Self) -> Self {
+        self + other
+    }
+
+    fn sub(self, other: Self) -> Self {
+        self - other
+    }
+
+    fn mul(self, other: Self) -> Self {
+        self * other
+    }
+
+    fn inv(self) -> CtOption<Self> {
+        k256::Scalar::invert(&self)
+    }
+
+    fn random<R: RngCore + CryptoRng>(rng: &mut R) -> Self {
+        <k256::Scalar as k256::elliptic_curve::Field>::random(rng)
+    }
+}
+
+// Scalars are encoded in 32 big-endian bytes, as in SEC 1.
+#[cfg(feature = "secp256k1")]
+impl ScalarField for k256::Scalar {
+    const FORMAT_VERSION: u8 = 0x84;
+    const ENCODED_LEN: usize = 32;
+
+    fn write_canonical(&self, bytes: &mut [u8]) {
+        bytes.copy_from_slice(&self.to_bytes());
+    }
+
+    fn from_canonical(bytes: &[u8]) -> CtOption<Self> {
+        <k256::Scalar as k256::elliptic_curve::PrimeField>::from_repr(k256::FieldBytes::clone_from_slice(bytes))
+    }
+}
+
+/// A prime-order group written additively, whose scalars form a [`ScalarField`] and whose elements
+/// have a compressed encoding, used for the commitments of verifiable secret sharing.
+pub trait PrimeGroup: Copy + Eq + fmt::Debug {
+    /// The scalar field of the group.
+    type Scalar: ScalarField;
+
+    /// The length of the compressed encoding of an element.
+    const ENCODED_LEN: usize;
+
+    /// Returns the standard generator of the group.
+    fn generator() -> Self;
+
+    /// Returns a second generator of the group, whose discrete logarithm with respect to
+    /// [`PrimeGroup::generator`] is unknown, used by the commitments of Pedersen's scheme.
+    ///
+    /// The generator is derived by hashing a fixed tag with a counter until the digest is a valid
+    /// compressed encoding, so that nobody knows its discrete logarithm.
+    fn blinding_generator() -> Self;
+
+    /// Adds two elements of the group.
+    fn add(self, other: Self) -> Self;
+
+    /// Multiplies the element by a scalar, in constant-time with respect to the scalar.
+    fn mul(self, scalar: &Self::Scalar) -> Self;
+
+    /// Writes the compressed encoding of the element into `bytes`, of [`PrimeGroup::ENCODED_LEN`] bytes.
+    fn write_compressed(&self, bytes: &mut [u8]);
+
+    /// Parses an element from its compressed encoding, of [`PrimeGroup::ENCODED_LEN`] bytes.
+    ///
+    /// ## Returns
+    /// * The element, or none if the encoding is invalid.
+    fn from_compressed(bytes: &[u8]) -> Option<Self>;
+}
+
+// The Ristretto255 group, of prime order over Curve25519.
+impl PrimeGroup for RistrettoPoint {
+    type Scalar = Scalar;
+    const ENCODED_LEN: usize = 32;
+
+    fn generator() -> Self {
+        RISTRETTO_BASEPOINT_POINT
+    }
+
+    fn blinding_generator() -> Self {
+        hash_to_point(|digest| Self::from_compressed(digest))
+    }
+
+    fn add(self, other: Self) -> Self {
+        self + other
+    }
+
+    fn mul(self, scalar: &Scalar) -> Self {
+        self * scalar
+    }
+
+    fn write_compressed(&self, bytes: &mut [u8]) {
+        bytes.copy_from_slice(self.compress().as_bytes());
+    }
+
+    fn from_compressed(bytes: &[u8]) -> Option<Self> {
+        CompressedRistretto::from_slice(bytes).ok()?.decompress()
+    }
+}
+
+// The secp256k1 group, with points compressed as in SEC 1.
+#[cfg(feature = "secp256k1")]
+impl PrimeGroup for k256::ProjectivePoint {
+    type Scalar = k256::Scalar;
+    const ENCODED_LEN: usize = 33;
+
+    fn generator() -> Self {
+        k256::ProjectivePoint::GENERATOR
+    }
+
+    fn blinding_generator() -> Self {
+        // The digest is the x-coordinate of a point with an even y-coordinate.
+        hash_to_point(|digest| {
+            let mut bytes = [0x02; 33];
+            bytes[1..].copy_from_slice(digest);
+            Self::from_compressed(&bytes)
+        })
+    }
+
+    fn add(self, other: Self) -> Self {
+        self + other
+    }
+
+    fn mul(self, scalar: &k256::Scalar) -> Self {
+        self * scalar
+    }
+
+    fn write_compressed(&self, bytes: &mut [u8]) {
+        bytes.copy_from_slice(&GroupEncoding::to_bytes(self));
+    }
+
+    fn from_compressed(bytes: &[u8]) -> Option<Self> {
+        Option::from(<k256::ProjectivePoint as GroupEncoding>::from_bytes(
+            k256::CompressedPoint::from_slice(bytes),
+        ))
+    }
+}
+
+/// The tag hashed into the blinding generator of every group.
+const BLINDING_GENERATOR_TAG: &[u8] = b"shamirs pedersen blinding generator";
+
+/// Derives a point by try-and-increment, hashing the tag with an incrementing counter
+/// until `decode` accepts the SHA-256 digest.
+fn hash_to_point<G>(decode: impl Fn(&[u8; 32]) -> Option<G>) -> G {
+    (0u32..)
+        .find_map(|counter| {
+            let digest = Sha256::new()
+                .chain_update(BLINDING_GENERATOR_TAG)
+                .chain_update(counter.to_be_bytes())
+                .finalize();
+            decode(&digest.into())
+        })
+        .expect("no valid encoding among 2^32 digests")
+}
+
+/// A single share of a scalar of a prime-order group, consisting of a 2-byte x-coordinate and
+/// the scalar produced by evaluating the polynomial of the secret scalar.
+///
+/// Scalar shares carry the threshold and a random split identifier as wide shares do, and are encoded
+/// in the binary format of [`ScalarShare::to_bytes`], with the y-coordinate in its canonical encoding.
+#[derive(Clone)]
+pub struct ScalarShare<S: ScalarField> {
+    /// The x-coordinate at which the polynomial was evaluated.
+    x: u16,
+    /// The y-coordinate, a scalar of the same group as the secret.
+    y: S,
+    /// Minimum number of shares required to reconstruct the secret.
+    threshold: u16,
+    /// Random identifier shared by all shares of the same split.
+    id: u32,
+}
+
+impl<S: ScalarField> ScalarShare<S> {
+    /// Parses a scalar share from its binary format.
+    ///
+    /// ## Arguments
+    /// * `bytes` - The encoded share, as written by [`ScalarShare::to_bytes`].
+    ///
+    /// ## Returns
+    /// * The decoded share if successful; otherwise, an error.
+    ///
+    /// ## Errors
+    /// * Returns an error if the format version does not match the group, the length does not match
+    ///   the encoding of a scalar or the scalar is not canonically encoded.
+    pub fn from_bytes(bytes: &[u8]) -> Result<ScalarShare<S>, Error> {
+        let version = *bytes.first().ok_or(Error::ShareTooShort { index: 0, len: 0 })?;
+        if version != S::FORMAT_VERSION {
+            return Err(Error::UnsupportedVersion { version });
+        }
+        if bytes.len() != SCALAR_HEADER_LEN + S::ENCODED_LEN {
+            return Err(Error::InvalidBufferLength {
+                index: 0,
+                expected: SCALAR_HEADER_LEN + S::ENCODED_LEN,
+                found: bytes.len(),
+            });
+        }
+
+        let threshold = u16::from_be_bytes([bytes[1], bytes[2]]);
+        if threshold < 2 {
+            return Err(Error::ThresholdTooSmall {
+                threshold: threshold as usize,
+            });
+        }
+
+        // Non-canonical encodings are rejected, so that every share has a unique encoding.
+        let y = Option::from(S::from_canonical(&bytes[SCALAR_HEADER_LEN..])).ok_or(Error::ExceedsModulus)?;
+        Ok(ScalarShare {
+            x: u16::from_be_bytes([bytes[7], bytes[8]]),
+            y,
+            threshold,
+            id: u32::from_be_bytes([bytes[3], bytes[4], bytes[5], bytes[6]]),
+        })
+    }
+
+    /// Encodes the share into the binary format `(version, threshold, id, x, y)`, with the threshold,
+    /// the split identifier and the x-coordinate in big-endian and the y-coordinate in its canonical encoding.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(SCALAR_HEADER_LEN + S::ENCODED_LEN);
+        bytes.push(S::FORMAT_VERSION);
+        bytes.extend_from_slice(&self.threshold.to_be_bytes());
+        bytes.extend_from_slice(&self.id.to_be_bytes());
+        bytes.extend_from_slice(&self.x.to_be_bytes());
+        bytes.resize(SCALAR_HEADER_LEN + S::ENCODED_LEN, 0);
+        self.y.write_canonical(&mut bytes[SCALAR_HEADER_LEN..]);
+        bytes
+    }
+
+    /// Returns the x-coordinate of the share.
+    pub fn x(&self) -> u16 {
+        self.x
+    }
+
+    /// Returns the minimum number of shares required to reconstruct the secret.
+    pub fn threshold(&self) -> usize {
+        self.threshold as usize
+    }
+
+    /// Returns the random identifier shared by all shares of the same split.
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Returns the y-coordinate of the share.
+    pub fn y(&self) -> S {
+        self.y
+    }
+}
+
+// The y-coordinate is redacted to prevent shares from leaking into logs.
+impl<S: ScalarField> fmt::Debug for ScalarShare<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ScalarShare")
+            .field("x", &self.x)
+            .field("y", &"[REDACTED]")
+            .field("threshold", &self.threshold)
+            .field("id", &self.id)
+            .finish()
+    }
+}
+
+// The comparison of the coordinates is performed in constant-time,
+// while the metadata is considered public information.
+impl<S: ScalarField> PartialEq for ScalarShare<S> {
+    fn eq(&self, other: &Self) -> bool {
+        let coordinates = self.x.ct_eq(&other.x) & self.y.ct_eq(&other.y);
+        bool::from(coordinates) && self.threshold == other.threshold && self.id == other.id
+    }
+}
+
+impl<S: ScalarField> Eq for ScalarShare<S> {}
+
+// Clear memory associated with the coordinates when the share is dropped.
+impl<S: ScalarField> Drop for ScalarShare<S> {
+    fn drop(&mut self) {
+        self.x.zeroize();
+        self.y.zeroize();
+    }
+}
+
+/// Splits a scalar of a prime-order group, such as a private key, into multiple scalar shares.
+///
+/// Private keys of Ed25519 are shared as the clamped scalar derived from the seed, since the seed itself
+/// is not an element of the scalar field.
+///
+/// ## Arguments
+/// * `secret` - The scalar to be split.
+/// * `parts` - Total number of shares to create.
+/// * `threshold` - Minimum number of shares required to reconstruct the secret.
+///
+/// ## Returns
+/// * A vector of shares if successful; otherwise, an error.
+///
+/// ## Errors
+/// * Returns an error if parameters are invalid (e.g., `parts` < `threshold`).
+#[cfg(feature = "std")]
+pub fn split_scalar<S: ScalarField>(secret: &S, parts: usize, threshold: usize) -> Result<Vec<ScalarShare<S>>, Error> {
+    split_scalar_with_rng(secret, parts, threshold, &mut rand::thread_rng())
+}
+
+/// Splits a scalar of a prime-order group into multiple scalar shares,
+/// drawing randomness from the provided generator.
+///
+/// ## Arguments
+/// * `secret` - The scalar to be split.
+/// * `parts` - Total number of shares to create.
+/// * `threshold` - Minimum number of shares required to reconstruct the secret.
+/// * `rng` - A cryptographically secure random number generator.
+///
+/// ## Returns
+/// * A vector of shares if successful; otherwise, an error.
+///
+/// ## Errors
+/// * Returns an error if parameters are invalid (e.g., `parts` < `threshold`).
+pub fn split_scalar_with_rng<S: ScalarField, R: RngCore + CryptoRng>(
+    secret: &S,
+    parts: usize,
+    threshold: usize,
+    rng: &mut R,
+) -> Result<Vec<ScalarShare<S>>, Error> {
+    deal(secret, parts, threshold, rng).map(|(shares, _)| shares)
+}
+
+/// Generates the polynomial of the secret scalar and evaluates it at distinct random x-coordinates.
+///
+/// ## Returns
+/// * The shares and the polynomial, whose coefficients are committed to by verifiable schemes.
+pub(crate) fn deal<S: ScalarField, R: RngCore + CryptoRng>(
+    secret: &S,
+    parts: usize,
+    threshold: usize,
+    rng: &mut R,
+) -> Result<(Vec<ScalarShare<S>>, Polynomial<S>), Error> {
+    validate_parameters(parts, threshold, MAX_SCALAR_PARTS)?;
+
+    // Sample distinct non-zero x-coordinates, without shuffling all of them.
+    let x_coordinates = rand::seq::index::sample(rng, MAX_SCALAR_PARTS, parts);
+    let id = rng.gen();
+
+    // For a polynomial of degree `k−1`, you need `k` distinct points to uniquely determine it.
+    let polynomial = Polynomial::generate_with_rng(*secret, threshold - 1, rng);
+
+    let shares = x_coordinates
+        .iter()
+        .map(|x| {
+            let x = x as u16 + 1;
+            ScalarShare {
+                x,
+                y: polynomial.evaluate(scalar_from_u16(x)),
+                threshold: threshold as u16,
+                id,
+            }
+        })
+        .collect();
+    Ok((shares, polynomial))
+}
+
+/// Combines scalar shares to reconstruct the secret scalar.
+///
+/// ## Arguments
+/// * `shares` - Shares of the secret.
+///
+/// ## Returns
+/// * The original scalar if successful; otherwise, an error.
+///
+/// ## Errors
+/// * Returns an error if shares are inconsistent or insufficient.
+pub fn combine_scalar<S: ScalarField>(shares: &[ScalarShare<S>]) -> Result<S, Error> {
+    validate_scalar_shares(shares)?;
+
+    // The secret is the constant term of the polynomial, located at `x = 0`.
+    let x_samples: Vec<S> = shares.iter().map(|share| scalar_from_u16(share.x)).collect();
+    let mut y_samples: Vec<S> = shares.iter().map(|share| share.y).collect();
+    let secret = Polynomial::interpolate(&x_samples, &y_samples, S::zero());
+    y_samples.zeroize();
+
+    Ok(secret)
+}
+
+/// Converts an x-coordinate into a scalar, by doubling and adding over its bits from the most significant one.
+pub(crate) fn scalar_from_u16<S: Field>(x: u16) -> S {
+    (0..16).rev().fold(S::zero(), |scalar, bit| {
+        let doubled = scalar.add(scalar);
+        S::conditional_select(&doubled, &doubled.add(S::one()), (((x >> bit) & 1) as u8).into())
+    })
+}
+
+/// Validates that the shares are consistent and sufficient, with distinct non-zero x-coordinates.
+pub(crate) fn validate_scalar_shares<S: ScalarField>(shares: &[ScalarShare<S>]) -> Result<(), Error> {
+    let first = shares.first().ok_or(Error::TooFewShares {
+        provided: 0,
+        required: 2,
+    })?;
+
+    for (index, share) in shares.iter().enumerate().skip(1) {
+        if share.id != first.id {
+            return Err(Error::SplitIdMismatch { index });
+        }
+        if share.threshold != first.threshold {
+            return Err(Error::ThresholdMismatch {
+                index,
+                expected: first.threshold(),
+                found: share.threshold(),
+            });
+        }
+    }
+    if shares.len() < first.threshold() {
+        return Err(Error::TooFewShares {
+            provided: shares.len(),
+            required: first.threshold(),
+        });
+    }
+
+    unique_wide_x_coordinates(shares.iter().map(|share| share.x))
+}
+
+// Test cases for the shares of elliptic-curve scalars.
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    // The 'combine_scalar' function with shares from the 'split_scalar' function over Ristretto255.
+    #[test]
+    fn it_combines_scalar_shares() {
+        let secret = Scalar::from_bytes_mod_order([0x42; 32]);
+        let shares = split_scalar(&secret, 5, 3).expect("split failed");
+
+        assert_eq!(combine_scalar(&shares[..3]).expect("combine failed"), secret);
+        assert_eq!(combine_scalar(&shares[2..]).expect("combine failed"), secret);
+        assert_ne!(combine_scalar(&shares[..3]).expect("combine failed"), shares[0].y());
+
+        // The x-coordinates are converted into the scalars of the same integers.
+        assert_eq!(scalar_from_u16::<Scalar>(0xBEEF), Scalar::from(0xBEEFu16));
+    }
+
+    // Encoding and decoding of the binary format of scalar shares.
+    #[test]
+    fn it_encodes_canonical_scalars() {
+        let shares = split_scalar(&Scalar::from(42u8), 3, 2).expect("split failed");
+        let bytes = shares[0].to_bytes();
+        assert_eq!(bytes.len(), 9 + 32);
+        assert_eq!(bytes[..3], [0x83, 0, 2]);
+        assert_eq!(bytes[7..9], shares[0].x().to_be_bytes());
+        assert_eq!(bytes[9..], shares[0].y().to_bytes());
+
+        let decoded = ScalarShare::<Scalar>::from_bytes(&bytes).expect("decoding failed");
+        assert_eq!(decoded, shares[0]);
+        assert_eq!(decoded.id(), shares[0].id());
+
+        // The group order itself is not a canonical encoding of a scalar.
+        let mut bytes = bytes;
+        bytes[9..].copy_from_slice(&(Scalar::ZERO - Scalar::ONE).to_bytes());
+        bytes[9] = bytes[9].wrapping_add(1);
+        assert_eq!(ScalarShare::<Scalar>::from_bytes(&bytes), Err(Error::ExceedsModulus));
+        assert_eq!(
+            ScalarShare::<Scalar>::from_bytes(&bytes[..40]),
+            Err(Error::InvalidBufferLength {
+                index: 0,
+                expected: 41,
+                found: 40
+            })
+        );
+    }
+
+    // A decoded share at `x = 0` is reported as such, rather than as a duplicate.
+    #[test]
+    fn it_fails_to_combine_zero_x_coordinate() {
+        let shares = split_scalar(&Scalar::from(42u8), 5, 3).expect("split failed");
+        let mut bytes = shares[1].to_bytes();
+        bytes[7..9].fill(0);
+        let zero = ScalarShare::<Scalar>::from_bytes(&bytes).expect("decoding failed");
+
+        let selected = [shares[0].clone(), zero, shares[2].clone()];
+        assert_eq!(combine_scalar(&selected), Err(Error::ZeroXCoordinate { index: 1 }));
+    }
+
+    // The 'combine_scalar' function over secp256k1, whose shares cannot be mistaken for Ristretto255 shares.
+    #[cfg(feature = "secp256k1")]
+    #[test]
+    fn it_combines_secp256k1_scalars() {
+        let secret = k256::Scalar::from(0xDEADBEEFu64);
+        let shares = split_scalar(&secret, 4, 2).expect("split failed");
+        assert_eq!(combine_scalar(&shares[2..]).expect("combine failed"), secret);
+
+        let bytes = shares[0].to_bytes();
+        assert_eq!(ScalarShare::<k256::Scalar>::from_bytes(&bytes), Ok(shares[0].clone()));
+        assert_eq!(
+            ScalarShare::<Scalar>::from_bytes(&bytes),
+            Err(Error::UnsupportedVersion { version: 0x84 })
+        );
+    }
+
+    // Invalid parameters and insufficient or mixed shares are reported.
+    #[test]
+    fn it_reports_scalar_errors() {
+        let secret = Scalar::from(7u8);
+        assert_eq!(
+            split_scalar(&secret, 70000, 3),
+            Err(Error::PartsExceedField {
+                parts: 70000,
+                max: MAX_SCALAR_PARTS
+            })
+        );
+
+        let shares = split_scalar(&secret, 5, 3).expect("split failed");
+        assert_eq!(
+            combine_scalar(&shares[..2]),
+            Err(Error::TooFewShares {
+                provided: 2,
+                required: 3
+            })
+        );
+        let duplicate = [shares[0].clone(), shares[1].clone(), shares[0].clone()];
+        assert_eq!(
+            combine_scalar(&duplicate),
+            Err(Error::DuplicateXCoordinate { index: 2 })
+        );
+
+        let other = split_scalar(&secret, 5, 3).expect("split failed");
+        let mixed = [shares[0].clone(), shares[1].clone(), other[2].clone()];
+        assert_eq!(combine_scalar(&mixed), Err(Error::SplitIdMismatch { index: 2 }));
+    }
+}
@@ -0,0 +1,570 @@
+use crate::{
+    checked::DIGEST_X,
+    field::Gf256,
+    ops,
+    polynomial::Polynomial,
+    share::{Header, NONCE_LEN},
+    Error, Share,
+};
+use alloc::{vec, vec::Vec};
+use core::fmt;
+use rand::{CryptoRng, Rng, RngCore};
+use zeroize::Zeroize;
+
+/// The version of the binary format of refresh messages written by [`RefreshMessage::to_bytes`].
+pub const REFRESH_MESSAGE_FORMAT_VERSION: u8 = 0x89;
+
+/// The length of the header in the binary format of refresh messages: version (1 byte),
+/// split identifier (4 bytes), epoch (4 bytes), sender (1 byte), recipient (1 byte) and nonce.
+const MESSAGE_HEADER_LEN: usize = 11 + NONCE_LEN;
+
+/// A sub-share sent by a participant of the distributed refresh to another participant.
+///
+/// The sub-share holds the evaluations, at the x-coordinate of the recipient, of the zero-intercept
+/// polynomials of the sender, one for each byte of the secret. It must be delivered over a confidential
+/// and authenticated channel, since anyone learning the sub-shares sent to a holder can undo its refresh.
+#[derive(Clone)]
+pub struct RefreshMessage {
+    /// Random identifier of the split whose shares are refreshed.
+    id: u32,
+    /// The epoch of the shares before the refresh.
+    epoch: u32,
+    /// The x-coordinate of the sender.
+    from: u8,
+    /// The x-coordinate of the recipient.
+    to: u8,
+    /// The random nonce of the sender, which is chained into the refresh transcript hash.
+    nonce: [u8; NONCE_LEN],
+    /// The evaluations of the polynomials of the sender at the x-coordinate of the recipient.
+    sub_share: Vec<u8>,
+}
+
+impl RefreshMessage {
+    /// Parses a refresh message from its binary format.
+    ///
+    /// ## Arguments
+    /// * `bytes` - The encoded message, as written by [`RefreshMessage::to_bytes`].
+    ///
+    /// ## Returns
+    /// * The decoded message if successful; otherwise, an error.
+    ///
+    /// ## Errors
+    /// * Returns an error if the format version is unsupported or the message is truncated.
+    pub fn from_bytes(bytes: &[u8]) -> Result<RefreshMessage, Error> {
+        let version = *bytes.first().ok_or(Error::ShareTooShort { index: 0, len: 0 })?;
+        if version != REFRESH_MESSAGE_FORMAT_VERSION {
+            return Err(Error::UnsupportedVersion { version });
+        }
+        // At least one byte of the sub-share is required after the header.
+        if bytes.len() <= MESSAGE_HEADER_LEN {
+            return Err(Error::ShareTooShort {
+                index: 0,
+                len: bytes.len(),
+            });
+        }
+
+        Ok(RefreshMessage {
+            id: u32::from_be_bytes([bytes[1], bytes[2], bytes[3], bytes[4]]),
+            epoch: u32::from_be_bytes([bytes[5], bytes[6], bytes[7], bytes[8]]),
+            from: bytes[9],
+            to: bytes[10],
+            nonce: bytes[11..MESSAGE_HEADER_LEN].try_into().expect("nonce length"),
+            sub_share: bytes[MESSAGE_HEADER_LEN..].to_vec(),
+        })
+    }
+
+    /// Encodes the message into the binary format `(version, id, epoch, from, to, nonce, sub-share)`,
+    /// with the split identifier and the epoch in big-endian.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(MESSAGE_HEADER_LEN + self.sub_share.len());
+        bytes.push(REFRESH_MESSAGE_FORMAT_VERSION);
+        bytes.extend_from_slice(&self.id.to_be_bytes());
+        bytes.extend_from_slice(&self.epoch.to_be_bytes());
+        bytes.push(self.from);
+        bytes.push(self.to);
+        bytes.extend_from_slice(&self.nonce);
+        bytes.extend_from_slice(&self.sub_share);
+        bytes
+    }
+
+    /// Returns the random identifier of the split whose shares are refreshed.
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Returns the epoch of the shares before the refresh.
+    pub fn epoch(&self) -> u32 {
+        self.epoch
+    }
+
+    /// Returns the x-coordinate of the sender.
+    pub fn from(&self) -> u8 {
+        self.from
+    }
+
+    /// Returns the x-coordinate of the recipient.
+    pub fn to(&self) -> u8 {
+        self.to
+    }
+}
+
+// The sub-share is redacted to prevent it from leaking into logs.
+impl fmt::Debug for RefreshMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RefreshMessage")
+            .field("id", &self.id)
+            .field("epoch", &self.epoch)
+            .field("from", &self.from)
+            .field("to", &self.to)
+            .field("sub_share", &format_args!("[REDACTED; {}]", self.sub_share.len()))
+            .finish()
+    }
+}
+
+// Clear memory associated with the sub-share when the message is dropped.
+impl Drop for RefreshMessage {
+    fn drop(&mut self) {
+        self.sub_share.zeroize();
+    }
+}
+
+/// The phase of a participant in the distributed refresh.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RefreshPhase {
+    /// The participant has not yet dealt its sub-shares, but may already receive sub-shares of others.
+    Pending,
+    /// The participant has dealt its sub-shares and is collecting the sub-shares of others.
+    Dealt,
+    /// The participant has produced its refreshed share, and accepts no further messages.
+    Finished,
+}
+
+/// A holder taking part in the distributed proactive refresh of the shares of a split,
+/// in which no party ever sees the shares of the others.
+///
+/// Every participant deals a polynomial with a zero intercept for each byte of the secret, and sends
+/// its evaluation at the x-coordinate of every other participant in a [`RefreshMessage`] through
+/// [`RefreshParty::deal`]. Once a participant has dealt and has received the sub-shares of all other
+/// participants with [`RefreshParty::receive`], [`RefreshParty::finish`] adds them to its share. Since
+/// the sum of the polynomials has a zero intercept, the refreshed shares reconstruct the same secret,
+/// while old shares can no longer be combined with refreshed ones.
+///
+/// Shares with metadata move to the next epoch, whose transcript hash chains the random nonces
+/// of all participants, so that [`combine_shares`](crate::combine_shares) rejects mixed epochs.
+///
+/// For shares of [`split_checked_with_rng`](crate::split_checked_with_rng), the polynomials also vanish
+/// at the x-coordinate of the digest share, which preserves it as `refresh_checked` does and requires
+/// a threshold of at least 3. Checked shares converted into the legacy byte layout are not recognized,
+/// and their digest share is changed by the refresh.
+///
+/// All holders of the split must take part, as the shares of absent holders are not refreshed.
+pub struct RefreshParty {
+    /// The x-coordinate of the share of this participant.
+    x: u8,
+    /// The metadata of the split, absent for shares in the legacy byte layout.
+    header: Option<Header>,
+    /// Minimum number of shares required to reconstruct the secret.
+    threshold: usize,
+    /// The x-coordinates of all participants, including this one.
+    participants: Vec<u8>,
+    /// Whether the sub-share of the participant at the same index was added.
+    received: Vec<bool>,
+    /// The nonces of the participants at the same index.
+    nonces: Vec<[u8; NONCE_LEN]>,
+    /// The y-coordinates of the share, to which the sub-shares are added.
+    refreshed: Vec<u8>,
+    /// The current phase of the protocol.
+    phase: RefreshPhase,
+}
+
+impl RefreshParty {
+    /// Creates a participant of the distributed refresh from its share.
+    ///
+    /// ## Arguments
+    /// * `share` - The current share of this participant.
+    /// * `participants` - The x-coordinates of the shares of all participants, including this one.
+    /// * `threshold` - Minimum number of shares required to reconstruct the secret.
+    ///
+    /// ## Returns
+    /// * The participant in the [`RefreshPhase::Pending`] phase if successful; otherwise, an error.
+    ///
+    /// ## Errors
+    /// * Returns an error if the threshold does not match the share, the participants are fewer than
+    ///   the threshold, contain duplicates or zero, or do not include the share.
+    /// * Returns [`Error::RefreshThresholdTooSmall`] if the share was split in checked mode with a threshold of `2`.
+    pub fn new(share: &Share, participants: &[u8], threshold: usize) -> Result<RefreshParty, Error> {
+        if threshold < 2 {
+            return Err(Error::ThresholdTooSmall { threshold });
+        }
+        if let Some(expected) = share.threshold() {
+            if expected != threshold {
+                return Err(Error::ThresholdMismatch {
+                    index: 0,
+                    expected,
+                    found: threshold,
+                });
+            }
+        }
+        // The polynomials of checked shares are determined by the secret and the digest share alone.
+        if share.is_checked() && threshold < 3 {
+            return Err(Error::RefreshThresholdTooSmall { threshold, min: 3 });
+        }
+
+        let mut seen = [false; 256];
+        for (index, &x) in participants.iter().enumerate() {
+            if x == 0 {
+                return Err(Error::InvalidParticipant { x });
+            }
+            if seen[x as usize] {
+                return Err(Error::DuplicateXCoordinate { index });
+            }
+            seen[x as usize] = true;
+        }
+        if !seen[share.x() as usize] {
+            return Err(Error::InvalidParticipant { x: share.x() });
+        }
+        if participants.len() < threshold {
+            return Err(Error::TooFewShares {
+                provided: participants.len(),
+                required: threshold,
+            });
+        }
+
+        Ok(RefreshParty {
+            x: share.x(),
+            header: share.header(),
+            threshold,
+            participants: participants.to_vec(),
+            received: vec![false; participants.len()],
+            nonces: vec![[0u8; NONCE_LEN]; participants.len()],
+            refreshed: share.y().to_vec(),
+            phase: RefreshPhase::Pending,
+        })
+    }
+
+    /// Deals the sub-shares of this participant, one message for every other participant.
+    ///
+    /// ## Returns
+    /// * The messages to be delivered to the other participants if successful; otherwise, an error.
+    ///
+    /// ## Errors
+    /// * Returns [`Error::UnexpectedRefreshPhase`] if the participant has already dealt.
+    #[cfg(feature = "std")]
+    pub fn deal(&mut self) -> Result<Vec<RefreshMessage>, Error> {
+        self.deal_with_rng(&mut rand::thread_rng())
+    }
+
+    /// Deals the sub-shares of this participant, drawing randomness from the provided generator.
+    ///
+    /// ## Arguments
+    /// * `rng` - A cryptographically secure random number generator.
+    ///
+    /// ## Returns
+    /// * The messages to be delivered to the other participants if successful; otherwise, an error.
+    ///
+    /// ## Errors
+    /// * Returns [`Error::UnexpectedRefreshPhase`] if the participant has already dealt.
+    pub fn deal_with_rng<R: RngCore + CryptoRng>(&mut self, rng: &mut R) -> Result<Vec<RefreshMessage>, Error> {
+        if self.phase != RefreshPhase::Pending {
+            return Err(Error::UnexpectedRefreshPhase);
+        }
+
+        let (id, epoch) = (self.id(), self.epoch());
+        let nonce = rng.gen();
+        let mut messages: Vec<_> = self
+            .participants
+            .iter()
+            .filter(|&&to| to != self.x)
+            .map(|&to| RefreshMessage {
+                id,
+                epoch,
+                from: self.x,
+                to,
+                nonce,
+                sub_share: vec![0u8; self.refreshed.len()],
+            })
+            .collect();
+
+        // The polynomials of checked shares are multiplied by `(x - 255)`, which preserves the digest share.
+        let roots: &[u8] = if self.is_checked() { &[DIGEST_X] } else { &[] };
+        let evaluate = |polynomial: &Polynomial<Gf256>, x: u8| {
+            let value = polynomial.evaluate(Gf256(x)).0;
+            roots
+                .iter()
+                .fold(value, |value, &root| ops::mult(value, ops::add(x, root)))
+        };
+
+        for (idx, y) in self.refreshed.iter_mut().enumerate() {
+            // Every byte is refreshed by its own polynomial with f(0) = 0, which maintains the original secret.
+            let polynomial = Polynomial::generate_with_rng(Gf256(0), self.threshold - 1 - roots.len(), rng);
+            *y = ops::add(*y, evaluate(&polynomial, self.x));
+            for message in messages.iter_mut() {
+                message.sub_share[idx] = evaluate(&polynomial, message.to);
+            }
+        }
+
+        let own = self.position(self.x).expect("participant of its own refresh");
+        self.received[own] = true;
+        self.nonces[own] = nonce;
+        self.phase = RefreshPhase::Dealt;
+        Ok(messages)
+    }
+
+    /// Adds the sub-share of another participant to the share of this participant.
+    ///
+    /// ## Arguments
+    /// * `message` - The message received from another participant.
+    ///
+    /// ## Returns
+    /// * `Ok(())` if the sub-share was added; otherwise, an error.
+    ///
+    /// ## Errors
+    /// * Returns an error if the message belongs to a different split, is not addressed to this participant,
+    ///   comes from an unknown participant or one whose sub-share was already added, or has a different length.
+    /// * Returns [`Error::UnexpectedRefreshPhase`] if the participant has already finished.
+    pub fn receive(&mut self, message: &RefreshMessage) -> Result<(), Error> {
+        if self.phase == RefreshPhase::Finished {
+            return Err(Error::UnexpectedRefreshPhase);
+        }
+        if message.id != self.id() {
+            return Err(Error::SplitIdMismatch { index: 0 });
+        }
+        if message.epoch != self.epoch() {
+            return Err(Error::EpochMismatch {
+                index: 0,
+                expected: self.epoch(),
+                found: message.epoch,
+            });
+        }
+
+        let sender = self
+            .position(message.from)
+            .filter(|&sender| message.to == self.x && message.from != self.x && !self.received[sender])
+            .ok_or(Error::UnexpectedRefreshMessage { from: message.from })?;
+        if message.sub_share.len() != self.refreshed.len() {
+            return Err(Error::InvalidBufferLength {
+                index: 0,
+                expected: self.refreshed.len(),
+                found: message.sub_share.len(),
+            });
+        }
+
+        for (y, &sub_share) in self.refreshed.iter_mut().zip(message.sub_share.iter()) {
+            *y = ops::add(*y, sub_share);
+        }
+        self.received[sender] = true;
+        self.nonces[sender] = message.nonce;
+        Ok(())
+    }
+
+    /// Produces the refreshed share once this participant has dealt and received all sub-shares.
+    ///
+    /// ## Returns
+    /// * The refreshed share if successful; otherwise, an error.
+    ///
+    /// ## Errors
+    /// * Returns [`Error::RefreshIncomplete`] if sub-shares are missing, including the own sub-share
+    ///   of a participant that has not dealt yet.
+    /// * Returns [`Error::UnexpectedRefreshPhase`] if the participant has already finished.
+    pub fn finish(&mut self) -> Result<Share, Error> {
+        if self.phase == RefreshPhase::Finished {
+            return Err(Error::UnexpectedRefreshPhase);
+        }
+        let missing = self.received.iter().filter(|&&received| !received).count();
+        if missing > 0 {
+            return Err(Error::RefreshIncomplete { missing });
+        }
+
+        // The nonces are ordered by x-coordinate, so that all participants derive the same transcript hash.
+        let header = self.header.map(|header| {
+            let mut nonces: Vec<_> = self.participants.iter().copied().zip(self.nonces.iter()).collect();
+            nonces.sort_unstable_by_key(|&(x, _)| x);
+            header.next_epoch(nonces)
+        });
+
+        self.phase = RefreshPhase::Finished;
+        Ok(Share::new(self.x, core::mem::take(&mut self.refreshed), header))
+    }
+
+    /// Returns the x-coordinate of the share of this participant.
+    pub fn x(&self) -> u8 {
+        self.x
+    }
+
+    /// Returns the current phase of the protocol.
+    pub fn phase(&self) -> RefreshPhase {
+        self.phase
+    }
+
+    /// Returns the identifier of the split carried by the messages, zero for shares without metadata.
+    fn id(&self) -> u32 {
+        self.header.map_or(0, |header| header.id)
+    }
+
+    /// Returns the epoch carried by the messages, zero for shares without metadata.
+    fn epoch(&self) -> u32 {
+        self.header.map_or(0, |header| header.epoch)
+    }
+
+    /// Returns `true` if the share was split in checked mode, whose digest share must be preserved.
+    fn is_checked(&self) -> bool {
+        matches!(self.header, Some(Header { checked: true, .. }))
+    }
+
+    /// Returns the index of the participant at the x-coordinate `x`.
+    fn position(&self, x: u8) -> Option<usize> {
+        self.participants.iter().position(|&participant| participant == x)
+    }
+}
+
+// The accumulated share is redacted to prevent it from leaking into logs.
+impl fmt::Debug for RefreshParty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RefreshParty")
+            .field("x", &self.x)
+            .field("participants", &self.participants)
+            .field("phase", &self.phase)
+            .finish()
+    }
+}
+
+// Clear memory associated with the accumulated share when the participant is dropped.
+impl Drop for RefreshParty {
+    fn drop(&mut self) {
+        self.refreshed.zeroize();
+    }
+}
+
+// Test cases for the distributed refresh protocol.
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::{combine_shares, split_shares};
+    use rand::seq::SliceRandom;
+
+    // Runs the protocol between all holders in-process, delivering the encoded messages in random order.
+    fn simulate(shares: &[Share], threshold: usize) -> Vec<Share> {
+        let participants: Vec<u8> = shares.iter().map(Share::x).collect();
+        let mut parties: Vec<_> = shares
+            .iter()
+            .map(|share| RefreshParty::new(share, &participants, threshold).expect("invalid participant"))
+            .collect();
+
+        let mut messages = Vec::new();
+        for party in parties.iter_mut() {
+            messages.extend(party.deal().expect("deal failed"));
+        }
+        messages.shuffle(&mut rand::thread_rng());
+
+        for message in messages {
+            let received = RefreshMessage::from_bytes(&message.to_bytes()).expect("decoding failed");
+            let recipient = parties
+                .iter_mut()
+                .find(|party| party.x() == received.to())
+                .expect("unknown recipient");
+            recipient.receive(&received).expect("receive failed");
+        }
+
+        parties
+            .iter_mut()
+            .map(|party| party.finish().expect("finish failed"))
+            .collect()
+    }
+
+    // The refreshed shares reconstruct the same secret, and cannot be mixed with the old shares.
+    #[test]
+    fn it_refreshes_shares_between_parties() {
+        let secret = b"distributed_refresh";
+        let shares = split_shares(secret, 5, 3).expect("split failed");
+
+        let refreshed = simulate(&shares, 3);
+        let refreshed = simulate(&refreshed, 3);
+        for (old, new) in shares.iter().zip(refreshed.iter()) {
+            assert_eq!(
+                (old.x(), old.id(), old.threshold()),
+                (new.x(), new.id(), new.threshold())
+            );
+            assert_ne!(old.y(), new.y());
+        }
+        assert_eq!(combine_shares(&refreshed[..3]).expect("combine failed"), secret);
+        assert_eq!(combine_shares(&refreshed[2..]).expect("combine failed"), secret);
+
+        // Every participant derives the same epoch and transcript hash, which reject the old shares.
+        assert!(refreshed.iter().all(|share| share.epoch() == Some(2)));
+        assert!(refreshed
+            .iter()
+            .all(|share| share.transcript() == refreshed[0].transcript()));
+        let mixed = [refreshed[0].clone(), shares[1].clone(), refreshed[2].clone()];
+        assert_eq!(
+            combine_shares(&mixed),
+            Err(Error::EpochMismatch {
+                index: 1,
+                expected: 2,
+                found: 0
+            })
+        );
+    }
+
+    // The digest share of checked shares is preserved, as by the 'refresh_checked' function.
+    #[test]
+    fn it_refreshes_checked_shares_between_parties() {
+        let secret = b"a_sixteen_byte_secret";
+        let shares = crate::split_checked(secret, 5, 3).expect("split failed");
+
+        let refreshed = simulate(&shares, 3);
+        assert!(refreshed.iter().all(Share::is_checked));
+        assert_ne!(refreshed[0].y(), shares[0].y());
+        assert_eq!(
+            crate::combine_checked(&refreshed[1..4]).expect("combine failed"),
+            secret
+        );
+
+        // With a threshold of two, the polynomials cannot be refreshed without changing the digest share.
+        let shares = crate::split_checked(secret, 3, 2).expect("split failed");
+        let participants: Vec<u8> = shares.iter().map(Share::x).collect();
+        assert_eq!(
+            RefreshParty::new(&shares[0], &participants, 2).unwrap_err(),
+            Error::RefreshThresholdTooSmall { threshold: 2, min: 3 }
+        );
+    }
+
+    // Misdirected, duplicated and premature operations are rejected by the state machine.
+    #[test]
+    fn it_enforces_refresh_protocol() {
+        let shares = split_shares(b"secret", 3, 2).expect("split failed");
+        let participants = [shares[0].x(), shares[1].x(), shares[2].x()];
+        let mut first = RefreshParty::new(&shares[0], &participants, 2).expect("invalid participant");
+        let mut second = RefreshParty::new(&shares[1], &participants, 2).expect("invalid participant");
+
+        assert_eq!(first.finish(), Err(Error::RefreshIncomplete { missing: 3 }));
+        let messages = first.deal().expect("deal failed");
+        assert_eq!(first.deal().unwrap_err(), Error::UnexpectedRefreshPhase);
+        assert_eq!(first.phase(), RefreshPhase::Dealt);
+
+        let to_second = messages.iter().find(|message| message.to() == second.x()).unwrap();
+        let to_third = messages.iter().find(|message| message.to() != second.x()).unwrap();
+        assert_eq!(
+            second.receive(to_third),
+            Err(Error::UnexpectedRefreshMessage { from: first.x() })
+        );
+        assert_eq!(second.receive(to_second), Ok(()));
+        assert_eq!(
+            second.receive(to_second),
+            Err(Error::UnexpectedRefreshMessage { from: first.x() })
+        );
+        assert_eq!(second.finish(), Err(Error::RefreshIncomplete { missing: 2 }));
+
+        assert_eq!(
+            RefreshParty::new(&shares[0], &participants[1..], 2).unwrap_err(),
+            Error::InvalidParticipant { x: shares[0].x() }
+        );
+        assert_eq!(
+            RefreshParty::new(&shares[0], &participants, 3).unwrap_err(),
+            Error::ThresholdMismatch {
+                index: 0,
+                expected: 2,
+                found: 3
+            }
+        );
+    }
+}
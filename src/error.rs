@@ -0,0 +1,260 @@
+use core::fmt;
+
+/// Errors returned by the splitting, combining and refreshing operations.
+///
+/// Every variant carries the offending values, so callers can match on the cause
+/// of the failure instead of inspecting a message. New variants may be added in minor releases,
+/// therefore matches outside of this crate require a wildcard arm.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The `threshold` is lower than the minimum of two shares.
+    ThresholdTooSmall { threshold: usize },
+    /// The `threshold` is higher than the total number of `parts`.
+    ThresholdExceedsParts { threshold: usize, parts: usize },
+    /// The number of `parts` exceeds the available non-zero x-coordinates of the field.
+    PartsExceedField { parts: usize, max: usize },
+    /// The secret to be split is empty.
+    EmptySecret,
+    /// The share at `index` is too short to hold both a payload and an x-coordinate.
+    ShareTooShort { index: usize, len: usize },
+    /// The payload of the share at `index` differs in length from the payload of the first share.
+    InconsistentShareLength {
+        index: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// The share at `index` has an x-coordinate that was already seen in a previous share.
+    DuplicateXCoordinate { index: usize },
+    /// The share at `index` has the x-coordinate zero, at which the polynomial evaluates to the secret.
+    ZeroXCoordinate { index: usize },
+    /// Fewer shares were provided than are required.
+    TooFewShares { provided: usize, required: usize },
+    /// The share is encoded in an unsupported format `version`.
+    UnsupportedVersion { version: u8 },
+    /// The share at `index` belongs to a different split than the first share.
+    SplitIdMismatch { index: usize },
+    /// The share at `index` declares a different threshold than expected.
+    ThresholdMismatch {
+        index: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// The secret is shorter than the `min` length required by the split mode.
+    SecretTooShort { len: usize, min: usize },
+    /// The reconstructed secret does not match its embedded digest.
+    IntegrityCheckFailed,
+    /// More shares are corrupted than the `max` that can be corrected.
+    TooManyFaultyShares { max: usize },
+    /// The share buffer at `index` does not have the length required by the size of the secret.
+    InvalidBufferLength {
+        index: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// The padding of a secret split into 16-bit symbols is malformed.
+    InvalidPadding,
+    /// The secret or a coordinate of a share over a prime field is not less than the modulus.
+    ExceedsModulus,
+    /// The differences of the x-coordinates of the shares are not invertible, since the modulus is not prime.
+    NonInvertibleCoordinates,
+    /// The share at `index` does not match the published commitments of the dealer.
+    ShareVerificationFailed { index: usize },
+    /// A commitment is not the encoding of a valid group element.
+    InvalidCommitment,
+    /// The x-coordinate `x` is zero or does not identify a participant of the refresh.
+    InvalidParticipant { x: u8 },
+    /// The refresh message from the participant at `from` is not addressed to this party or was already received.
+    UnexpectedRefreshMessage { from: u8 },
+    /// The refresh cannot be finished before the sub-shares of the `missing` participants are received.
+    RefreshIncomplete { missing: usize },
+    /// The operation is not allowed in the current phase of the refresh protocol.
+    UnexpectedRefreshPhase,
+    /// The share at `index` was refreshed a different number of times than expected.
+    EpochMismatch { index: usize, expected: u32, found: u32 },
+    /// The share at `index` was produced by a different refresh of the same epoch.
+    TranscriptMismatch { index: usize },
+    /// The `threshold` is lower than the `min` required to refresh the shares of the split mode.
+    RefreshThresholdTooSmall { threshold: usize, min: usize },
+    /// The shares were split in checked mode and must be refreshed with `refresh_checked`,
+    /// which preserves their digest share.
+    CheckedRefreshRequired,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::ThresholdTooSmall { threshold } => {
+                write!(f, "threshold must be at least 2, got {threshold}")
+            }
+            Error::ThresholdExceedsParts { threshold, parts } => {
+                write!(f, "threshold ({threshold}) exceeds the number of parts ({parts})")
+            }
+            Error::PartsExceedField { parts, max } => {
+                write!(f, "number of parts ({parts}) exceeds the maximum of {max}")
+            }
+            Error::EmptySecret => write!(f, "secret must not be empty"),
+            Error::ShareTooShort { index, len } => {
+                write!(f, "share {index} is too short ({len} bytes)")
+            }
+            Error::InconsistentShareLength { index, expected, found } => {
+                write!(f, "share {index} has length {found}, expected {expected}")
+            }
+            Error::DuplicateXCoordinate { index } => {
+                write!(f, "share {index} has a duplicate x-coordinate")
+            }
+            Error::ZeroXCoordinate { index } => {
+                write!(f, "share {index} has the x-coordinate zero")
+            }
+            Error::TooFewShares { provided, required } => {
+                // The crate only reports fewer shares than required; values constructed otherwise must not panic.
+                write!(
+                    f,
+                    "need {} more shares ({provided} of {required})",
+                    required.saturating_sub(*provided)
+                )
+            }
+            Error::UnsupportedVersion { version } => {
+                write!(f, "unsupported share format version {version}")
+            }
+            Error::SplitIdMismatch { index } => {
+                write!(f, "share {index} belongs to a different split")
+            }
+            Error::ThresholdMismatch { index, expected, found } => {
+                write!(f, "share {index} has threshold {found}, expected {expected}")
+            }
+            Error::SecretTooShort { len, min } => {
+                write!(f, "secret length ({len}) is below the minimum of {min}")
+            }
+            Error::IntegrityCheckFailed => {
+                write!(f, "reconstructed secret failed the integrity check")
+            }
+            Error::TooManyFaultyShares { max } => {
+                write!(f, "more than {max} shares are corrupted")
+            }
+            Error::InvalidBufferLength { index, expected, found } => {
+                write!(f, "share buffer {index} has length {found}, expected {expected}")
+            }
+            Error::InvalidPadding => write!(f, "reconstructed secret has invalid padding"),
+            Error::ExceedsModulus => write!(f, "value is not less than the prime modulus"),
+            Error::NonInvertibleCoordinates => {
+                write!(
+                    f,
+                    "x-coordinates of the shares are not invertible, the modulus is not prime"
+                )
+            }
+            Error::ShareVerificationFailed { index } => {
+                write!(f, "share {index} does not match the commitments")
+            }
+            Error::InvalidCommitment => write!(f, "commitment is not a valid group element"),
+            Error::InvalidParticipant { x } => {
+                write!(f, "x-coordinate {x} is not a valid participant of the refresh")
+            }
+            Error::UnexpectedRefreshMessage { from } => {
+                write!(f, "unexpected refresh message from participant {from}")
+            }
+            Error::RefreshIncomplete { missing } => {
+                write!(f, "refresh is missing the sub-shares of {missing} participants")
+            }
+            Error::UnexpectedRefreshPhase => {
+                write!(f, "operation is not allowed in the current phase of the refresh")
+            }
+            Error::EpochMismatch { index, expected, found } => {
+                write!(
+                    f,
+                    "share {index} is from refresh epoch {found}, expected epoch {expected}"
+                )
+            }
+            Error::TranscriptMismatch { index } => {
+                write!(f, "share {index} belongs to a different refresh of the same epoch")
+            }
+            Error::RefreshThresholdTooSmall { threshold, min } => {
+                write!(
+                    f,
+                    "threshold must be at least {min} to refresh the shares, got {threshold}"
+                )
+            }
+            Error::CheckedRefreshRequired => {
+                write!(f, "checked shares must be refreshed with refresh_checked")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+// Converts the error for the streaming functions, which report invalid parameters as `InvalidInput`
+// and invalid shares as `InvalidData`. The original error is available through `get_ref`.
+#[cfg(feature = "std")]
+impl From<Error> for std::io::Error {
+    fn from(error: Error) -> std::io::Error {
+        let kind = match error {
+            Error::ThresholdTooSmall { .. }
+            | Error::ThresholdExceedsParts { .. }
+            | Error::PartsExceedField { .. }
+            | Error::EmptySecret
+            | Error::SecretTooShort { .. }
+            | Error::InvalidBufferLength { .. } => std::io::ErrorKind::InvalidInput,
+            _ => std::io::ErrorKind::InvalidData,
+        };
+        std::io::Error::new(kind, error)
+    }
+}
+
+// Test cases for the `error` module.
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    // The `Display` implementation includes the offending values.
+    #[test]
+    fn it_displays_offending_values() {
+        assert_eq!(
+            Error::ThresholdExceedsParts { threshold: 4, parts: 3 }.to_string(),
+            "threshold (4) exceeds the number of parts (3)"
+        );
+        assert_eq!(
+            Error::InconsistentShareLength {
+                index: 2,
+                expected: 12,
+                found: 11
+            }
+            .to_string(),
+            "share 2 has length 11, expected 12"
+        );
+        assert_eq!(
+            Error::DuplicateXCoordinate { index: 1 }.to_string(),
+            "share 1 has a duplicate x-coordinate"
+        );
+        assert_eq!(
+            Error::TooFewShares {
+                provided: 1,
+                required: 3
+            }
+            .to_string(),
+            "need 2 more shares (1 of 3)"
+        );
+    }
+
+    // The error can be boxed as a `std::error::Error` trait object.
+    #[test]
+    fn it_converts_into_boxed_error() {
+        let boxed: Box<dyn std::error::Error> = Error::EmptySecret.into();
+        assert_eq!(boxed.to_string(), "secret must not be empty");
+    }
+
+    // The error converts into an I/O error of the matching kind, preserving the original error.
+    #[test]
+    fn it_converts_into_io_error() {
+        let io_error = std::io::Error::from(Error::EmptySecret);
+        assert_eq!(io_error.kind(), std::io::ErrorKind::InvalidInput);
+
+        let io_error = std::io::Error::from(Error::SplitIdMismatch { index: 1 });
+        assert_eq!(io_error.kind(), std::io::ErrorKind::InvalidData);
+        assert_eq!(
+            io_error.get_ref().and_then(|error| error.downcast_ref::<Error>()),
+            Some(&Error::SplitIdMismatch { index: 1 })
+        );
+    }
+}
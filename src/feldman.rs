@@ -0,0 +1,328 @@
+use crate::{
+    curve::{deal, scalar_from_u16, PrimeGroup, ScalarField, ScalarShare},
+    Error,
+};
+use alloc::vec::Vec;
+use rand::{CryptoRng, RngCore};
+
+/// The version of the binary format of Feldman commitments written by [`FeldmanCommitments::to_bytes`].
+pub const FELDMAN_FORMAT_VERSION: u8 = 0x85;
+
+/// The length of the header in the binary format of commitments: version (1 byte),
+/// group (1 byte) and split identifier (4 bytes).
+pub(crate) const COMMITMENTS_HEADER_LEN: usize = 6;
+
+/// The shares of a Feldman split, with the commitments published to all holders.
+type FeldmanSplit<G> = (Vec<ScalarShare<<G as PrimeGroup>::Scalar>>, FeldmanCommitments<G>);
+
+/// The public commitments of a dealer to the coefficients of the polynomial of a secret scalar,
+/// which allow every holder to verify its share without learning anything beyond `g^secret`.
+///
+/// The i-th commitment is `g^{a_i}`, written additively as `a_i * G`, for the i-th coefficient of the
+/// polynomial, therefore the number of commitments is equal to the threshold. The first commitment is
+/// `g^secret`, which is the public key when the secret is a private key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FeldmanCommitments<G: PrimeGroup> {
+    /// The commitments to the coefficients, ordered from the intercept up to the highest-degree term.
+    points: Vec<G>,
+    /// Random identifier shared by the shares of the same split.
+    id: u32,
+}
+
+impl<G: PrimeGroup> FeldmanCommitments<G> {
+    /// Parses the commitments from their binary format.
+    ///
+    /// ## Arguments
+    /// * `bytes` - The encoded commitments, as written by [`FeldmanCommitments::to_bytes`].
+    ///
+    /// ## Returns
+    /// * The decoded commitments if successful; otherwise, an error.
+    ///
+    /// ## Errors
+    /// * Returns an error if the format version or the group is unsupported, or the commitments
+    ///   are truncated or not valid elements of the group.
+    pub fn from_bytes(bytes: &[u8]) -> Result<FeldmanCommitments<G>, Error> {
+        let (id, points) = decode_points(FELDMAN_FORMAT_VERSION, bytes)?;
+        Ok(FeldmanCommitments { points, id })
+    }
+
+    /// Encodes the commitments into the binary format `(version, group, id, C_0, C_1, .., C_{t-1})`,
+    /// with the split identifier in big-endian and every commitment in its compressed encoding.
+    ///
+    /// The group is identified by the format version of the shares of its scalars.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        encode_points(FELDMAN_FORMAT_VERSION, self.id, &self.points)
+    }
+
+    /// Returns the minimum number of shares required to reconstruct the secret.
+    pub fn threshold(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Returns the random identifier shared by the shares of the same split.
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Returns the commitments to the coefficients, ordered from the intercept up to the highest-degree term.
+    pub fn points(&self) -> &[G] {
+        &self.points
+    }
+
+    /// Returns the commitment `g^secret` to the secret.
+    pub fn secret_commitment(&self) -> G {
+        self.points[0]
+    }
+}
+
+/// Splits a scalar into multiple shares with Feldman's verifiable secret sharing over the group `G`.
+///
+/// The shares are those of [`split_scalar`](crate::split_scalar), combined by [`combine_scalar`](crate::combine_scalar),
+/// while the commitments are published to all holders, who verify their shares with [`verify_share`].
+/// The commitments reveal `g^secret`, which is the public key when the secret is a private key.
+///
+/// ## Arguments
+/// * `secret` - The scalar to be split.
+/// * `parts` - Total number of shares to create.
+/// * `threshold` - Minimum number of shares required to reconstruct the secret.
+///
+/// ## Returns
+/// * A vector of shares and the commitments if successful; otherwise, an error.
+///
+/// ## Errors
+/// * Returns an error if parameters are invalid (e.g., `parts` < `threshold`).
+#[cfg(feature = "std")]
+pub fn split_feldman<G: PrimeGroup>(
+    secret: &G::Scalar,
+    parts: usize,
+    threshold: usize,
+) -> Result<FeldmanSplit<G>, Error> {
+    split_feldman_with_rng(secret, parts, threshold, &mut rand::thread_rng())
+}
+
+/// Splits a scalar into multiple shares with Feldman's verifiable secret sharing over the group `G`,
+/// drawing randomness from the provided generator.
+///
+/// ## Arguments
+/// * `secret` - The scalar to be split.
+/// * `parts` - Total number of shares to create.
+/// * `threshold` - Minimum number of shares required to reconstruct the secret.
+/// * `rng` - A cryptographically secure random number generator.
+///
+/// ## Returns
+/// * A vector of shares and the commitments if successful; otherwise, an error.
+///
+/// ## Errors
+/// * Returns an error if parameters are invalid (e.g., `parts` < `threshold`).
+pub fn split_feldman_with_rng<G: PrimeGroup, R: RngCore + CryptoRng>(
+    secret: &G::Scalar,
+    parts: usize,
+    threshold: usize,
+    rng: &mut R,
+) -> Result<FeldmanSplit<G>, Error> {
+    let (shares, polynomial) = deal(secret, parts, threshold, rng)?;
+
+    let points = polynomial
+        .coefficients()
+        .iter()
+        .map(|coefficient| G::generator().mul(coefficient))
+        .collect();
+    let id = shares[0].id();
+
+    Ok((shares, FeldmanCommitments { points, id }))
+}
+
+/// Verifies a share against the commitments of the dealer, by checking that `g^{y}` is equal to
+/// the product of the commitments `C_i^{x^i}`, i.e., the commitment to the polynomial evaluated at `x`.
+///
+/// ## Arguments
+/// * `share` - The share received from the dealer.
+/// * `commitments` - The commitments published by the dealer.
+///
+/// ## Returns
+/// * `Ok(())` if the share is consistent with the commitments; otherwise, an error.
+///
+/// ## Errors
+/// * Returns an error if the share belongs to a different split or threshold, or does not match the commitments.
+pub fn verify_share<G: PrimeGroup>(
+    share: &ScalarShare<G::Scalar>,
+    commitments: &FeldmanCommitments<G>,
+) -> Result<(), Error> {
+    validate_share(share, commitments.id, commitments.threshold())?;
+
+    if G::generator().mul(&share.y()) == evaluate_points(&commitments.points, share.x()) {
+        Ok(())
+    } else {
+        Err(Error::ShareVerificationFailed { index: 0 })
+    }
+}
+
+/// Validates that the share belongs to the split of the commitments, at a non-zero x-coordinate.
+pub(crate) fn validate_share<S: ScalarField>(share: &ScalarShare<S>, id: u32, threshold: usize) -> Result<(), Error> {
+    if share.id() != id {
+        return Err(Error::SplitIdMismatch { index: 0 });
+    }
+    if share.threshold() != threshold {
+        return Err(Error::ThresholdMismatch {
+            index: 0,
+            expected: threshold,
+            found: share.threshold(),
+        });
+    }
+    if share.x() == 0 {
+        return Err(Error::ShareVerificationFailed { index: 0 });
+    }
+    Ok(())
+}
+
+/// Evaluates the polynomial committed to by `points` at `x` in the exponent, using Horner's method.
+pub(crate) fn evaluate_points<G: PrimeGroup>(points: &[G], x: u16) -> G {
+    let x = scalar_from_u16::<G::Scalar>(x);
+    let mut points = points.iter().rev();
+    let highest = *points.next().expect("threshold of at least two");
+    points.fold(highest, |result, &point| result.mul(&x).add(point))
+}
+
+/// Encodes the commitments of a split into the binary format `(version, group, id, points)`.
+pub(crate) fn encode_points<G: PrimeGroup>(version: u8, id: u32, points: &[G]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(COMMITMENTS_HEADER_LEN + points.len() * G::ENCODED_LEN);
+    bytes.push(version);
+    bytes.push(G::Scalar::FORMAT_VERSION);
+    bytes.extend_from_slice(&id.to_be_bytes());
+    bytes.resize(COMMITMENTS_HEADER_LEN + points.len() * G::ENCODED_LEN, 0);
+    for (point, encoding) in points
+        .iter()
+        .zip(bytes[COMMITMENTS_HEADER_LEN..].chunks_exact_mut(G::ENCODED_LEN))
+    {
+        point.write_compressed(encoding);
+    }
+    bytes
+}
+
+/// Decodes the commitments of a split from the binary format `(version, group, id, points)`.
+///
+/// ## Returns
+/// * The split identifier and the points if successful; otherwise, an error.
+pub(crate) fn decode_points<G: PrimeGroup>(version: u8, bytes: &[u8]) -> Result<(u32, Vec<G>), Error> {
+    if bytes.len() < COMMITMENTS_HEADER_LEN {
+        return Err(Error::InvalidCommitment);
+    }
+    if bytes[0] != version {
+        return Err(Error::UnsupportedVersion { version: bytes[0] });
+    }
+    if bytes[1] != G::Scalar::FORMAT_VERSION {
+        return Err(Error::UnsupportedVersion { version: bytes[1] });
+    }
+
+    // At least two commitments are required, one for each coefficient of a polynomial of degree one.
+    let encodings = &bytes[COMMITMENTS_HEADER_LEN..];
+    if encodings.len() % G::ENCODED_LEN != 0 || encodings.len() < 2 * G::ENCODED_LEN {
+        return Err(Error::InvalidCommitment);
+    }
+    let points = encodings
+        .chunks_exact(G::ENCODED_LEN)
+        .map(|encoding| G::from_compressed(encoding).ok_or(Error::InvalidCommitment))
+        .collect::<Result<_, _>>()?;
+
+    Ok((u32::from_be_bytes([bytes[2], bytes[3], bytes[4], bytes[5]]), points))
+}
+
+// Test cases for Feldman's verifiable secret sharing.
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::combine_scalar;
+    use curve25519_dalek::{RistrettoPoint, Scalar};
+
+    // Every share of 'split_feldman' is verified and the shares are combined with 'combine_scalar'.
+    #[test]
+    fn it_verifies_feldman_shares() {
+        let secret = Scalar::from_bytes_mod_order([0x42; 32]);
+        let (shares, commitments) = split_feldman::<RistrettoPoint>(&secret, 5, 3).expect("split failed");
+
+        assert_eq!(commitments.threshold(), 3);
+        assert_eq!(
+            commitments.secret_commitment(),
+            RistrettoPoint::generator().mul(&secret)
+        );
+        for share in shares.iter() {
+            assert_eq!(verify_share(share, &commitments), Ok(()));
+        }
+        assert_eq!(combine_scalar(&shares[2..]).expect("combine failed"), secret);
+    }
+
+    // Substituted, corrupted or mismatched shares are rejected.
+    #[test]
+    fn it_rejects_invalid_shares() {
+        let secret = Scalar::from(7u8);
+        let (shares, commitments) = split_feldman::<RistrettoPoint>(&secret, 5, 3).expect("split failed");
+
+        // A share with a corrupted y-coordinate.
+        let mut bytes = shares[0].to_bytes();
+        bytes[9] ^= 0x01;
+        let corrupted = ScalarShare::from_bytes(&bytes).expect("decoding failed");
+        assert_eq!(
+            verify_share(&corrupted, &commitments),
+            Err(Error::ShareVerificationFailed { index: 0 })
+        );
+
+        // A share of a different split, and a share claiming the x-coordinate of another share.
+        let (other, _) = split_feldman::<RistrettoPoint>(&secret, 5, 3).expect("split failed");
+        assert_eq!(
+            verify_share(&other[0], &commitments),
+            Err(Error::SplitIdMismatch { index: 0 })
+        );
+        let mut bytes = shares[0].to_bytes();
+        bytes[7..9].copy_from_slice(&shares[1].x().to_be_bytes());
+        let substituted = ScalarShare::from_bytes(&bytes).expect("decoding failed");
+        assert_eq!(
+            verify_share(&substituted, &commitments),
+            Err(Error::ShareVerificationFailed { index: 0 })
+        );
+    }
+
+    // Encoding and decoding of the binary format of the commitments.
+    #[test]
+    fn it_encodes_commitments() {
+        let (_, commitments) = split_feldman::<RistrettoPoint>(&Scalar::ONE, 3, 2).expect("split failed");
+        let bytes = commitments.to_bytes();
+        assert_eq!(bytes.len(), 6 + 2 * 32);
+        assert_eq!(bytes[..2], [FELDMAN_FORMAT_VERSION, 0x83]);
+        assert_eq!(FeldmanCommitments::from_bytes(&bytes), Ok(commitments.clone()));
+
+        assert_eq!(
+            FeldmanCommitments::<RistrettoPoint>::from_bytes(&bytes[..6 + 32]),
+            Err(Error::InvalidCommitment)
+        );
+        let mut invalid = bytes.clone();
+        invalid[6..38].fill(0xFF);
+        assert_eq!(
+            FeldmanCommitments::<RistrettoPoint>::from_bytes(&invalid),
+            Err(Error::InvalidCommitment)
+        );
+        invalid[1] = 0x84;
+        assert_eq!(
+            FeldmanCommitments::<RistrettoPoint>::from_bytes(&invalid),
+            Err(Error::UnsupportedVersion { version: 0x84 })
+        );
+    }
+
+    // Feldman's scheme over secp256k1, whose commitment to the secret is the public key.
+    #[cfg(feature = "secp256k1")]
+    #[test]
+    fn it_verifies_secp256k1_shares() {
+        let secret = k256::Scalar::from(0xDEADBEEFu64);
+        let (shares, commitments) = split_feldman::<k256::ProjectivePoint>(&secret, 4, 2).expect("split failed");
+
+        assert_eq!(
+            commitments.secret_commitment(),
+            k256::ProjectivePoint::GENERATOR * secret
+        );
+        for share in shares.iter() {
+            assert_eq!(verify_share(share, &commitments), Ok(()));
+        }
+        let decoded =
+            FeldmanCommitments::<k256::ProjectivePoint>::from_bytes(&commitments.to_bytes()).expect("decoding failed");
+        assert_eq!(verify_share(&shares[0], &decoded), Ok(()));
+    }
+}
@@ -0,0 +1,172 @@
+use crate::{ops, ops16};
+use core::fmt::Debug;
+use rand::{CryptoRng, Rng, RngCore};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+use zeroize::{DefaultIsZeroes, Zeroize};
+
+/// The arithmetic of a finite field, over which polynomials are generated, evaluated and interpolated.
+///
+/// Implementations are expected to perform every operation in constant-time with respect to the
+/// values of the elements, since the elements are coefficients or evaluations of secret polynomials.
+/// The constant-time comparison and selection are provided by the `subtle` supertraits,
+/// and the elements are cleared from memory through [`Zeroize`].
+pub trait Field: Copy + Eq + Debug + ConditionallySelectable + ConstantTimeEq + Zeroize {
+    /// Returns the additive identity of the field.
+    fn zero() -> Self;
+
+    /// Returns the multiplicative identity of the field.
+    fn one() -> Self;
+
+    /// Adds two elements of the field.
+    fn add(self, other: Self) -> Self;
+
+    /// Subtracts an element from another, which is equivalent to addition in fields of characteristic 2.
+    fn sub(self, other: Self) -> Self;
+
+    /// Multiplies two elements of the field.
+    fn mul(self, other: Self) -> Self;
+
+    /// Computes the multiplicative inverse of the element.
+    ///
+    /// ## Returns
+    /// * The inverse, or none if the element is zero.
+    fn inv(self) -> CtOption<Self>;
+
+    /// Draws a uniformly random element of the field from the provided generator.
+    fn random<R: RngCore + CryptoRng>(rng: &mut R) -> Self;
+
+    /// Checks in constant-time whether the element is zero.
+    fn is_zero(&self) -> Choice {
+        self.ct_eq(&Self::zero())
+    }
+}
+
+/// An element of `GF(2^8)` with the irreducible polynomial `x^8 + x^4 + x^3 + x + 1`,
+/// the field of every [`Share`](crate::Share).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Gf256(pub u8);
+
+/// An element of `GF(2^16)` with the irreducible polynomial `x^16 + x^12 + x^3 + x + 1`,
+/// the field of every [`WideShare`](crate::WideShare).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Gf65536(pub u16);
+
+/// Implements the traits required by [`Field`] for a binary field wrapping an unsigned integer,
+/// and the field itself on top of the operations of its module.
+macro_rules! binary_field {
+    ($field:ident, $ops:ident) => {
+        impl ConstantTimeEq for $field {
+            fn ct_eq(&self, other: &Self) -> Choice {
+                self.0.ct_eq(&other.0)
+            }
+        }
+
+        // Elements are compared in constant-time, since they are usually derived from the secret.
+        impl PartialEq for $field {
+            fn eq(&self, other: &Self) -> bool {
+                self.ct_eq(other).into()
+            }
+        }
+
+        impl Eq for $field {}
+
+        impl ConditionallySelectable for $field {
+            fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+                $field(ConditionallySelectable::conditional_select(&a.0, &b.0, choice))
+            }
+        }
+
+        impl DefaultIsZeroes for $field {}
+
+        impl Field for $field {
+            fn zero() -> Self {
+                $field(0)
+            }
+
+            fn one() -> Self {
+                $field(1)
+            }
+
+            fn add(self, other: Self) -> Self {
+                $field($ops::add(self.0, other.0))
+            }
+
+            fn sub(self, other: Self) -> Self {
+                // Subtraction is equivalent to addition in fields of characteristic 2.
+                $field($ops::add(self.0, other.0))
+            }
+
+            fn mul(self, other: Self) -> Self {
+                $field($ops::mult(self.0, other.0))
+            }
+
+            fn inv(self) -> CtOption<Self> {
+                // The inverse of one is computed in place of the undefined inverse of zero,
+                // which avoids branching on the value of the element.
+                let is_zero = self.is_zero();
+                let value = Self::conditional_select(&self, &Self::one(), is_zero);
+                CtOption::new($field($ops::inverse(value.0)), !is_zero)
+            }
+
+            fn random<R: RngCore + CryptoRng>(rng: &mut R) -> Self {
+                $field(rng.gen())
+            }
+        }
+    };
+}
+
+binary_field!(Gf256, ops);
+binary_field!(Gf65536, ops16);
+
+// Test cases for the field implementations.
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Checks the field axioms on a sample of elements.
+    fn check_axioms<F: Field>(elements: &[F]) {
+        for &a in elements {
+            assert_eq!(a.add(F::zero()), a);
+            assert_eq!(a.mul(F::one()), a);
+            assert_eq!(a.sub(a), F::zero());
+            assert_eq!(a.mul(F::zero()), F::zero());
+
+            let inverse = a.inv();
+            assert_eq!(bool::from(inverse.is_some()), a != F::zero());
+            if let Some(inverse) = Option::<F>::from(inverse) {
+                assert_eq!(a.mul(inverse), F::one());
+            }
+
+            for &b in elements {
+                assert_eq!(a.add(b), b.add(a));
+                assert_eq!(a.mul(b), b.mul(a));
+                assert_eq!(a.add(b).sub(b), a);
+                for &c in elements {
+                    assert_eq!(a.mul(b.add(c)), a.mul(b).add(a.mul(c)));
+                }
+            }
+        }
+    }
+
+    // The binary fields satisfy the field axioms.
+    #[test]
+    fn it_satisfies_field_axioms() {
+        let elements = [0x00, 0x01, 0x02, 0x53, 0xCA, 0xFF];
+        check_axioms(&elements.map(Gf256));
+        check_axioms(&[0x0000, 0x0001, 0x0002, 0x1000, 0xBEEF, 0xFFFF].map(Gf65536));
+    }
+
+    // The elements wrap the operations of their modules.
+    #[test]
+    fn it_wraps_field_operations() {
+        assert_eq!(Gf256(0x53).mul(Gf256(0xCA)), Gf256(0x01));
+        assert_eq!(Gf256(0x53).inv().unwrap(), Gf256(0xCA));
+        assert_eq!(Gf65536(0x8000).mul(Gf65536(0x0002)), Gf65536(0x100B));
+        assert!(bool::from(Gf256::zero().inv().is_none()));
+        assert!(bool::from(Gf65536::zero().inv().is_none()));
+        assert_eq!(
+            Gf256::conditional_select(&Gf256(1), &Gf256(2), Choice::from(1)),
+            Gf256(2)
+        );
+    }
+}
@@ -0,0 +1,232 @@
+use crate::{ops, polynomial, shuffle_x_coordinates, unique_x_samples, validate_parameters, Error, MAX_PARTS};
+use rand::{CryptoRng, Rng, RngCore};
+use zeroize::Zeroize;
+
+/// Splits a secret of `N` bytes into caller-provided share buffers, without allocating.
+///
+/// Every buffer receives a share in the legacy byte layout `(y1, y2, .., yn, x)` and must therefore be
+/// `N + 1` bytes long, e.g., `[[u8; 33]; 5]` for a 32-byte secret split into 5 shares.
+/// The number of buffers determines the number of shares.
+///
+/// ## Arguments
+/// * `secret` - The secret to be split.
+/// * `threshold` - Minimum number of shares required to reconstruct the secret.
+/// * `shares` - The buffers receiving the shares, one for each share.
+///
+/// ## Returns
+/// * Nothing if successful; otherwise, an error.
+///
+/// ## Errors
+/// * Returns an error if parameters are invalid (e.g., `parts` < `threshold`), the secret is empty
+///   or a buffer is not `N + 1` bytes long.
+#[cfg(feature = "std")]
+pub fn split_into<const N: usize, S: AsMut<[u8]>>(
+    secret: &[u8; N],
+    threshold: usize,
+    shares: &mut [S],
+) -> Result<(), Error> {
+    split_into_with_rng(secret, threshold, shares, &mut rand::thread_rng())
+}
+
+/// Splits a secret of `N` bytes into caller-provided share buffers, without allocating,
+/// drawing randomness from the provided generator.
+///
+/// See [`split_into`] for the layout of the share buffers.
+///
+/// ## Arguments
+/// * `secret` - The secret to be split.
+/// * `threshold` - Minimum number of shares required to reconstruct the secret.
+/// * `shares` - The buffers receiving the shares, one for each share.
+/// * `rng` - A cryptographically secure random number generator.
+///
+/// ## Returns
+/// * Nothing if successful; otherwise, an error.
+///
+/// ## Errors
+/// * Returns an error if parameters are invalid (e.g., `parts` < `threshold`), the secret is empty
+///   or a buffer is not `N + 1` bytes long.
+pub fn split_into_with_rng<const N: usize, S: AsMut<[u8]>, R: RngCore + CryptoRng>(
+    secret: &[u8; N],
+    threshold: usize,
+    shares: &mut [S],
+    rng: &mut R,
+) -> Result<(), Error> {
+    // Validate the input parameters.
+    validate_parameters(shares.len(), threshold, MAX_PARTS)?;
+    if N == 0 {
+        return Err(Error::EmptySecret);
+    }
+    for (index, share) in shares.iter_mut().enumerate() {
+        let found = share.as_mut().len();
+        if found != N + 1 {
+            return Err(Error::InvalidBufferLength {
+                index,
+                expected: N + 1,
+                found,
+            });
+        }
+    }
+
+    let mut x_coordinates = [0u8; MAX_PARTS];
+    shuffle_x_coordinates(&mut x_coordinates, rng);
+
+    // The x-coordinate of every share is stored in its last byte.
+    for (share, &x) in shares.iter_mut().zip(x_coordinates.iter()) {
+        share.as_mut()[N] = x;
+    }
+
+    // The coefficients of each polynomial are generated into a stack buffer of the maximum degree.
+    let mut coefficients = [0u8; MAX_PARTS];
+    let coefficients_len = threshold;
+
+    for (s_idx, &secret_byte) in secret.iter().enumerate() {
+        coefficients[0] = secret_byte;
+        rng.fill(&mut coefficients[1..coefficients_len]);
+
+        for share in shares.iter_mut() {
+            let share = share.as_mut();
+            share[s_idx] = polynomial::evaluate_coefficients(&coefficients[..coefficients_len], share[N]);
+        }
+    }
+
+    // Clear memory associated with the coefficients, which include the secret bytes.
+    coefficients.zeroize();
+
+    Ok(())
+}
+
+/// Combines shares of a secret of `N` bytes in the legacy byte layout `(y1, y2, .., yn, x)`,
+/// without allocating.
+///
+/// Every share must be `N + 1` bytes long. The secret is returned on the stack,
+/// and it's the responsibility of the caller to zeroize it once it's no longer required.
+///
+/// ## Arguments
+/// * `shares` - Shares of the secret.
+///
+/// ## Returns
+/// * The original secret if successful; otherwise, an error.
+///
+/// ## Errors
+/// * Returns an error if shares are not `N + 1` bytes long, have duplicate x-coordinates or are insufficient.
+pub fn combine_array<const N: usize, S: AsRef<[u8]>>(shares: &[S]) -> Result<[u8; N], Error> {
+    if shares.len() < 2 {
+        return Err(Error::TooFewShares {
+            provided: shares.len(),
+            required: 2,
+        });
+    }
+    for (index, share) in shares.iter().enumerate() {
+        let found = share.as_ref().len();
+        if found != N + 1 {
+            return Err(Error::InvalidBufferLength {
+                index,
+                expected: N + 1,
+                found,
+            });
+        }
+    }
+
+    // Any number of shares with unique x-coordinates fits into the 256 elements of `GF(2^8)`.
+    let mut x_samples = [0u8; 256];
+    unique_x_samples(shares.iter().map(|share| share.as_ref()[N]), &mut x_samples)?;
+    let x_samples = &x_samples[..shares.len()];
+
+    // The Lagrange weights at `x = 0` depend only on the x-coordinates, therefore they are computed once.
+    let mut weights = [0u8; 256];
+    let weights = &mut weights[..shares.len()];
+    polynomial::lagrange_weights(x_samples, 0, weights);
+
+    // Reconstruct every byte of the secret as the weighted sum of the y-coordinates, share by share.
+    let mut secret = [0u8; N];
+    for (share, &weight) in shares.iter().zip(weights.iter()) {
+        ops::mult_accumulate_slice(&mut secret, weight, &share.as_ref()[..N]);
+    }
+
+    Ok(secret)
+}
+
+// Test cases for the allocation-free split and combine functions.
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::{combine, split};
+
+    // The 'combine_array' function with shares from the 'split_into' function.
+    #[test]
+    fn it_splits_into_buffers() {
+        let secret = [0x2Au8; 32];
+
+        let mut shares = [[0u8; 33]; 5];
+        split_into(&secret, 3, &mut shares).expect("split failed");
+
+        assert_eq!(combine_array::<32, _>(&shares[..3]).expect("combine failed"), secret);
+        assert_eq!(combine_array::<32, _>(&shares[2..]).expect("combine failed"), secret);
+
+        // The shares are regular shares in the legacy byte layout.
+        let legacy: Vec<Vec<u8>> = shares.iter().map(|share| share.to_vec()).collect();
+        assert_eq!(combine(&legacy[1..4]).expect("combine failed"), secret);
+    }
+
+    // Share buffers can also be provided as mutable slices.
+    #[test]
+    fn it_splits_into_slices() {
+        let secret = *b"test_secret";
+
+        let (mut first, mut second, mut third) = ([0u8; 12], [0u8; 12], [0u8; 12]);
+        let mut shares: [&mut [u8]; 3] = [&mut first, &mut second, &mut third];
+        split_into(&secret, 2, &mut shares).expect("split failed");
+
+        let shares: [&[u8]; 2] = [&second, &third];
+        assert_eq!(combine_array(&shares).expect("combine failed"), secret);
+    }
+
+    // The 'combine_array' function with shares from the 'split' function.
+    #[test]
+    fn it_combines_split_shares_into_array() {
+        let secret = *b"test_secret";
+        let shares = split(&secret, 5, 3).expect("split failed");
+
+        assert_eq!(combine_array(&shares[..3]).expect("combine failed"), secret);
+    }
+
+    // Invalid parameters and buffers are reported.
+    #[test]
+    fn it_reports_fixed_size_errors() {
+        let mut shares = [[0u8; 4]; 3];
+        assert_eq!(
+            split_into(&[1u8, 2, 3], 4, &mut shares),
+            Err(Error::ThresholdExceedsParts { threshold: 4, parts: 3 })
+        );
+        assert_eq!(split_into(&[], 2, &mut shares), Err(Error::EmptySecret));
+        assert_eq!(
+            split_into(&[1u8, 2], 2, &mut shares),
+            Err(Error::InvalidBufferLength {
+                index: 0,
+                expected: 3,
+                found: 4
+            })
+        );
+
+        split_into(&[1u8, 2, 3], 2, &mut shares).expect("split failed");
+        assert_eq!(
+            combine_array::<2, _>(&shares),
+            Err(Error::InvalidBufferLength {
+                index: 0,
+                expected: 3,
+                found: 4
+            })
+        );
+        assert_eq!(
+            combine_array::<3, _>(&shares[..1]),
+            Err(Error::TooFewShares {
+                provided: 1,
+                required: 2
+            })
+        );
+        assert_eq!(
+            combine_array::<3, _>(&[shares[0], shares[1], shares[0]]),
+            Err(Error::DuplicateXCoordinate { index: 2 })
+        );
+    }
+}
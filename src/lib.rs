@@ -1,11 +1,261 @@
-#![forbid(unsafe_code)]
+#![cfg_attr(not(feature = "std"), no_std)]
+// Unsafe code is forbidden, except for the SIMD kernels isolated in the `simd` module.
+#![cfg_attr(not(feature = "simd"), forbid(unsafe_code))]
+#![cfg_attr(feature = "simd", deny(unsafe_code))]
 #![warn(clippy::all)]
 
+extern crate alloc;
+
+#[cfg(feature = "tokio")]
+mod async_stream;
+mod backend;
+mod checked;
+mod consistency;
+#[cfg(feature = "curve")]
+mod curve;
+#[cfg(feature = "refresh")]
+mod distributed;
+mod error;
+#[cfg(feature = "curve")]
+mod feldman;
+mod field;
+mod fixed;
+mod manifest;
 mod ops;
+mod ops16;
+#[cfg(feature = "curve")]
+mod pedersen;
 mod polynomial;
-use polynomial::Polynomial;
+#[cfg(feature = "prime")]
+mod prime;
+mod robust;
+mod share;
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+mod simd;
+#[cfg(feature = "std")]
+mod stream;
+mod wide;
+#[cfg(feature = "tokio")]
+pub use async_stream::{combine_async, split_async, split_async_with_rng};
+#[cfg(all(feature = "refresh", feature = "std"))]
+pub use checked::refresh_checked;
+#[cfg(feature = "refresh")]
+pub use checked::refresh_checked_with_rng;
+#[cfg(feature = "std")]
+pub use checked::split_checked;
+pub use checked::{combine_checked, split_checked_with_rng};
+pub use consistency::{verify_consistency, ConsistencyReport};
+#[cfg(all(feature = "curve", feature = "std"))]
+pub use curve::split_scalar;
+#[cfg(feature = "curve")]
+pub use curve::{combine_scalar, split_scalar_with_rng, PrimeGroup, ScalarField, ScalarShare, MAX_SCALAR_PARTS};
+// The scalars are those of `curve25519_dalek::Scalar` and, with the `secp256k1` feature, `k256::Scalar`.
+#[cfg(feature = "curve")]
+pub use curve25519_dalek;
+#[cfg(feature = "refresh")]
+pub use distributed::{RefreshMessage, RefreshParty, RefreshPhase, REFRESH_MESSAGE_FORMAT_VERSION};
+pub use error::Error;
+#[cfg(all(feature = "curve", feature = "std"))]
+pub use feldman::split_feldman;
+#[cfg(feature = "curve")]
+pub use feldman::{split_feldman_with_rng, verify_share, FeldmanCommitments, FELDMAN_FORMAT_VERSION};
+pub use field::{Field, Gf256, Gf65536};
+#[cfg(feature = "std")]
+pub use fixed::split_into;
+pub use fixed::{combine_array, split_into_with_rng};
+#[cfg(feature = "secp256k1")]
+pub use k256;
+#[cfg(feature = "std")]
+pub use manifest::split_committed;
+pub use manifest::{combine_committed, split_committed_with_rng, Manifest, MANIFEST_FORMAT_VERSION};
+#[cfg(all(feature = "curve", feature = "std"))]
+pub use pedersen::split_pedersen;
+#[cfg(feature = "curve")]
+pub use pedersen::{
+    combine_pedersen, split_pedersen_with_rng, verify_pedersen_share, PedersenCommitments, PedersenShare,
+    PEDERSEN_FORMAT_VERSION, PEDERSEN_SHARE_FORMAT_VERSION,
+};
+pub use polynomial::Polynomial;
+// The moduli of prime fields are declared with `crypto_bigint::impl_modulus!`.
+#[cfg(feature = "prime")]
+pub use crypto_bigint;
+#[cfg(all(feature = "prime", feature = "refresh", feature = "std"))]
+pub use prime::refresh_prime;
+#[cfg(all(feature = "prime", feature = "refresh"))]
+pub use prime::refresh_prime_with_rng;
+#[cfg(all(feature = "prime", feature = "std"))]
+pub use prime::split_prime;
+#[cfg(feature = "prime")]
+pub use prime::{combine_prime, split_prime_with_rng, PrimeShare, MAX_PRIME_PARTS, PRIME_FORMAT_VERSION};
+use rand::{CryptoRng, Rng, RngCore};
+pub use robust::{combine_robust, Recovery};
+use share::Header;
+#[cfg(feature = "refresh")]
+use share::NONCE_LEN;
+pub use share::{Share, CHECKED_FORMAT_VERSION, EPOCH_FORMAT_VERSION, FORMAT_VERSION};
+#[cfg(feature = "std")]
+pub use stream::{combine_stream, split_stream, split_stream_with_rng};
+#[cfg(feature = "std")]
+pub use wide::split_wide;
+pub use wide::{combine_wide, split_wide_with_rng, WideShare, MAX_WIDE_PARTS, WIDE_FORMAT_VERSION};
+use zeroize::Zeroize;
+
+use alloc::{vec, vec::Vec};
+
+/// The maximum number of shares, bounded by the non-zero elements of `GF(2^8)`.
+const MAX_PARTS: usize = 255;
+
+/// The number of secret bytes whose polynomials are generated and evaluated together by `split`,
+/// bounding the memory of the coefficients to `threshold` times this length.
+const SPLIT_CHUNK_LEN: usize = 4096;
+
+/// The number of chunks whose coefficients are generated before they are evaluated together.
+///
+/// With the `parallel` feature, the chunks of a batch are evaluated on the rayon thread pool,
+/// bounding the memory of the coefficients to `threshold` times the length of a batch.
+const SPLIT_BATCH_CHUNKS: usize = if cfg!(feature = "parallel") { 64 } else { 1 };
+
+/// Calls `f` with every chunk, on the rayon thread pool with the `parallel` feature, otherwise sequentially.
+///
+/// The chunks must be independent of each other, since they are processed in no particular order.
+fn for_each_chunk<T: Send>(chunks: impl Iterator<Item = T> + Send, f: impl Fn(T) + Send + Sync) {
+    #[cfg(feature = "parallel")]
+    rayon::iter::ParallelIterator::for_each(rayon::iter::ParallelBridge::par_bridge(chunks), f);
+    #[cfg(not(feature = "parallel"))]
+    chunks.for_each(f);
+}
+
+/// Validates the `parts` and `threshold` parameters shared by `split` and `refresh`,
+/// given the maximum number of x-coordinates available to the shares.
+fn validate_parameters(parts: usize, threshold: usize, max_parts: usize) -> Result<(), Error> {
+    if threshold < 2 {
+        return Err(Error::ThresholdTooSmall { threshold });
+    }
+    if parts > max_parts {
+        return Err(Error::PartsExceedField { parts, max: max_parts });
+    }
+    if threshold > parts {
+        return Err(Error::ThresholdExceedsParts { threshold, parts });
+    }
+    Ok(())
+}
+
+/// Parses shares from the legacy byte layout, reporting the index of the first invalid share.
+fn from_legacy(shares: &[Vec<u8>]) -> Result<Vec<Share>, Error> {
+    shares
+        .iter()
+        .enumerate()
+        .map(|(index, share)| {
+            Share::try_from(share.as_slice()).map_err(|_| Error::ShareTooShort {
+                index,
+                len: share.len(),
+            })
+        })
+        .collect()
+}
+
+/// Generates a random permutation of the non-zero x-coordinates in `1..=max`.
+fn shuffled_x_coordinates(max: u8, rng: &mut impl Rng) -> Vec<u8> {
+    let mut x_coordinates = vec![0u8; max as usize];
+    shuffle_x_coordinates(&mut x_coordinates, rng);
+    x_coordinates
+}
+
+/// Fills the buffer with a random permutation of the non-zero x-coordinates in `1..=x_coordinates.len()`,
+/// without allocating.
+fn shuffle_x_coordinates(x_coordinates: &mut [u8], rng: &mut impl Rng) {
+    // Generate a sequence of non-zero values in GF(2^8)
+    for (x, value) in x_coordinates.iter_mut().zip(1..=u8::MAX) {
+        *x = value;
+    }
+
+    // Shuffle to create a random permutation of the x-coordinates.
+    rand::seq::SliceRandom::shuffle(x_coordinates, rng);
+}
+
+/// Validates that the shares are of the same length and belong to the same split.
+///
+/// ## Returns
+/// * The minimum number of shares required for reconstruction, as declared by the metadata
+///   of the shares, or `2` for shares without metadata.
+fn validate_shares(shares: &[Share]) -> Result<usize, Error> {
+    let first = shares.first().ok_or(Error::TooFewShares {
+        provided: 0,
+        required: 2,
+    })?;
+
+    for (index, share) in shares.iter().enumerate().skip(1) {
+        // Ensure all shares are of the same length.
+        if share.payload_len() != first.payload_len() {
+            return Err(Error::InconsistentShareLength {
+                index,
+                expected: first.payload_len(),
+                found: share.payload_len(),
+            });
+        }
+
+        // Ensure all shares originate from the same split and agree on the threshold.
+        match (first.header(), share.header()) {
+            (None, None) => {}
+            (Some(expected), Some(found)) if expected.id == found.id => {
+                if expected.threshold != found.threshold {
+                    return Err(Error::ThresholdMismatch {
+                        index,
+                        expected: expected.threshold as usize,
+                        found: found.threshold as usize,
+                    });
+                }
+                // Ensure stale and refreshed shares are not mixed, since they lie on different polynomials.
+                if expected.epoch != found.epoch {
+                    return Err(Error::EpochMismatch {
+                        index,
+                        expected: expected.epoch,
+                        found: found.epoch,
+                    });
+                }
+                if expected.transcript != found.transcript {
+                    return Err(Error::TranscriptMismatch { index });
+                }
+            }
+            _ => return Err(Error::SplitIdMismatch { index }),
+        }
+    }
+
+    Ok(first.threshold().unwrap_or(2))
+}
+
+/// Validates the shares against a `threshold` provided by the caller, which must agree
+/// with the threshold declared by the metadata of the shares, if any.
+fn validate_threshold(shares: &[Share], threshold: usize) -> Result<(), Error> {
+    if threshold < 2 {
+        return Err(Error::ThresholdTooSmall { threshold });
+    }
+    validate_shares(shares)?;
+
+    if let Some(expected) = shares[0].threshold() {
+        if expected != threshold {
+            return Err(Error::ThresholdMismatch {
+                index: 0,
+                expected,
+                found: threshold,
+            });
+        }
+    }
+    if shares.len() < threshold {
+        return Err(Error::TooFewShares {
+            provided: shares.len(),
+            required: threshold,
+        });
+    }
+
+    Ok(())
+}
+
 /// Splits a secret into multiple shares.
 ///
+/// Randomness is drawn from `rand::thread_rng`, which is only available with the `std` feature.
+/// In `no_std` environments, a generator is provided through [`split_shares_with_rng`] instead.
+///
 /// ## Arguments
 /// * `secret` - The secret to be split.
 /// * `threshold` - Minimum number of shares required to reconstruct the secret.
@@ -15,161 +265,478 @@ use polynomial::Polynomial;
 /// * A vector of shares if successful; otherwise, an error.
 ///
 /// ## Errors
-/// * Returns an error if parameters are invalid (e.g., `parts` < `threshold`).
-pub fn split(secret: &[u8], parts: usize, threshold: usize) -> Result<Vec<Vec<u8>>, Box<dyn std::error::Error>> {
+/// * Returns an error if parameters are invalid (e.g., `parts` < `threshold`) or the secret is empty.
+#[cfg(feature = "std")]
+pub fn split_shares(secret: &[u8], parts: usize, threshold: usize) -> Result<Vec<Share>, Error> {
+    split_shares_with_rng(secret, parts, threshold, &mut rand::thread_rng())
+}
+
+/// Splits a secret into multiple shares, drawing randomness from the provided generator.
+///
+/// The generator must implement `CryptoRng`, therefore non-cryptographic generators
+/// are rejected at compile time.
+///
+/// ```compile_fail
+/// struct Counter(u64);
+///
+/// impl rand::RngCore for Counter {
+///     fn next_u32(&mut self) -> u32 { self.next_u64() as u32 }
+///     fn next_u64(&mut self) -> u64 { self.0 += 1; self.0 }
+///     fn fill_bytes(&mut self, dest: &mut [u8]) { dest.iter_mut().for_each(|b| *b = self.next_u32() as u8) }
+///     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> { Ok(self.fill_bytes(dest)) }
+/// }
+///
+/// shamirs::split_shares_with_rng(b"secret", 5, 3, &mut Counter(0)).unwrap();
+/// ```
+///
+/// ## Arguments
+/// * `secret` - The secret to be split.
+/// * `threshold` - Minimum number of shares required to reconstruct the secret.
+/// * `parts` - Total number of shares to create.
+/// * `rng` - A cryptographically secure random number generator.
+///
+/// ## Returns
+/// * A vector of shares if successful; otherwise, an error.
+///
+/// ## Errors
+/// * Returns an error if parameters are invalid (e.g., `parts` < `threshold`) or the secret is empty.
+pub fn split_shares_with_rng<R: RngCore + CryptoRng>(
+    secret: &[u8],
+    parts: usize,
+    threshold: usize,
+    rng: &mut R,
+) -> Result<Vec<Share>, Error> {
     // Validate the input parameters.
-    if parts < threshold || parts > 255 || !(2..=255).contains(&threshold) || secret.is_empty() {
-        return Err("invalid input parameters".into());
+    validate_parameters(parts, threshold, MAX_PARTS)?;
+    if secret.is_empty() {
+        return Err(Error::EmptySecret);
     }
 
-    // Generate a sequence of non-zero values in GF(2^8)
-    let mut x_coordinates: Vec<_> = (1..=255).collect();
+    let x_coordinates = shuffled_x_coordinates(MAX_PARTS as u8, rng);
 
-    // Shuffle to create a random permutation of the x-coordinates.
-    let mut rng = rand::thread_rng();
-    rand::seq::SliceRandom::shuffle(x_coordinates.as_mut_slice(), &mut rng);
+    // Every share carries the threshold and a random identifier of the split,
+    // which allows `combine_shares` to detect insufficient or mixed shares.
+    let header = Header::new(threshold as u8, rng.gen());
 
-    // Set `share_size` to be equal to the length of the secret.
-    let share_size = secret.len();
     // Initialize the output vector to store shares where each share
-    // will consist of the y-coordinates plus one additional byte
-    // for the x-coordinate.
-    let mut shares = vec![vec![0u8; share_size + 1]; parts];
-
-    // Assign the x-coordinates to the last position of each share.
-    for idx in 0..parts {
-        shares[idx][share_size] = x_coordinates[idx];
-    }
+    // will consist of an x-coordinate and the y-coordinates
+    // in the size of the secret.
+    let mut shares: Vec<_> = x_coordinates[..parts]
+        .iter()
+        .map(|&x| Share::new(x, vec![0u8; secret.len()], Some(header)))
+        .collect();
 
     // For a polynomial of degree `k−1`, you need `k` distinct points to uniquely determine it,
-    // therefor we generate a polynomial of degree `threshold - 1`.
-    let degree = (threshold - 1) as u8;
-
-    // For each byte in the secret, create a polynomial and evaluate it at each x-coordinate.
-    for (s_idx, &secret_byte) in secret.iter().enumerate() {
-        // Generate a polynomial for the current byte of the secret.
-        let polynomial = Polynomial::generate(secret_byte, degree);
-
-        for p_idx in 0..parts {
-            // Access the x-coordinate for the current share.
-            let x = x_coordinates[p_idx];
-            // Evaluate the polynomial at the x-coordinate. This calculates
-            // the y-value of the polynomial, effectively generating a part
-            // of the share.
-            let y = polynomial.evaluate(x);
-            // Assign the evaluated y-value to the current share.
-            shares[p_idx][s_idx] = y;
+    // therefor we generate polynomials with `threshold` coefficients.
+    //
+    // The coefficients of the polynomials of a chunk of the secret are laid out column-wise, with the
+    // bytes of the secret as the intercepts followed by `threshold - 1` columns of random coefficients,
+    // so that every share is evaluated over whole slices at once.
+    let batch_len = SPLIT_BATCH_CHUNKS * SPLIT_CHUNK_LEN;
+    let mut columns = vec![0u8; threshold * batch_len.min(secret.len())];
+
+    for (b_idx, batch) in secret.chunks(batch_len).enumerate() {
+        let columns = &mut columns[..threshold * batch.len()];
+
+        // The coefficients are drawn chunk by chunk in the order of the secret, so that the shares
+        // only depend on the generator and not on the order in which the chunks are evaluated.
+        for (chunk, columns) in batch
+            .chunks(SPLIT_CHUNK_LEN)
+            .zip(columns.chunks_mut(threshold * SPLIT_CHUNK_LEN))
+        {
+            columns[..chunk.len()].copy_from_slice(chunk);
+            rng.fill(&mut columns[chunk.len()..]);
         }
+
+        // Collect the parts of every share that belong to each chunk, along with the x-coordinate of the share.
+        let offset = b_idx * batch_len;
+        let mut targets: Vec<Vec<(u8, &mut [u8])>> = batch
+            .chunks(SPLIT_CHUNK_LEN)
+            .map(|_| Vec::with_capacity(parts))
+            .collect();
+        for share in shares.iter_mut() {
+            let x = share.x();
+            let y = &mut share.y_mut()[offset..offset + batch.len()];
+            for (targets, y) in targets.iter_mut().zip(y.chunks_mut(SPLIT_CHUNK_LEN)) {
+                targets.push((x, y));
+            }
+        }
+
+        for_each_chunk(
+            columns.chunks(threshold * SPLIT_CHUNK_LEN).zip(targets),
+            |(columns, targets)| {
+                for (x, y) in targets {
+                    // Evaluate the polynomials at the x-coordinate of the current share,
+                    // effectively generating a part of the share.
+                    polynomial::evaluate_columns(columns, x, y);
+                }
+            },
+        );
     }
 
+    // Clear memory associated with the coefficients, which include the secret.
+    columns.zeroize();
+
     Ok(shares)
 }
 
+/// Splits a secret into multiple shares in the legacy byte layout `(y1, y2, .., yn, x)`.
+///
+/// This is a compatibility wrapper over [`split_shares`].
+///
+/// The legacy byte layout carries no threshold or split identifier, therefore [`combine`] cannot detect
+/// insufficient or mixed shares and returns an incorrect secret for them instead of an error. New code
+/// should use [`split_shares`] and [`combine_shares`], whose shares are encoded with [`Share::to_bytes`].
+///
+/// ## Arguments
+/// * `secret` - The secret to be split.
+/// * `threshold` - Minimum number of shares required to reconstruct the secret.
+/// * `parts` - Total number of shares to create.
+///
+/// ## Returns
+/// * A vector of shares if successful; otherwise, an error.
+///
+/// ## Errors
+/// * Returns an error if parameters are invalid (e.g., `parts` < `threshold`) or the secret is empty.
+#[cfg(feature = "std")]
+pub fn split(secret: &[u8], parts: usize, threshold: usize) -> Result<Vec<Vec<u8>>, Error> {
+    split_with_rng(secret, parts, threshold, &mut rand::thread_rng())
+}
+
+/// Splits a secret into multiple shares in the legacy byte layout `(y1, y2, .., yn, x)`,
+/// drawing randomness from the provided generator.
+///
+/// This is a compatibility wrapper over [`split_shares_with_rng`].
+///
+/// As for `split`, the shares carry no metadata and are not protected against insufficient or mixed shares.
+///
+/// ## Arguments
+/// * `secret` - The secret to be split.
+/// * `threshold` - Minimum number of shares required to reconstruct the secret.
+/// * `parts` - Total number of shares to create.
+/// * `rng` - A cryptographically secure random number generator.
+///
+/// ## Returns
+/// * A vector of shares if successful; otherwise, an error.
+///
+/// ## Errors
+/// * Returns an error if parameters are invalid (e.g., `parts` < `threshold`) or the secret is empty.
+pub fn split_with_rng<R: RngCore + CryptoRng>(
+    secret: &[u8],
+    parts: usize,
+    threshold: usize,
+    rng: &mut R,
+) -> Result<Vec<Vec<u8>>, Error> {
+    let shares = split_shares_with_rng(secret, parts, threshold, rng)?;
+    Ok(shares.into_iter().map(Vec::from).collect())
+}
+
 /// Generates update keys and refreshes the shares
 ///
 /// ## Arguments
 /// * `shares` - Current shares to be refreshed
+/// * `threshold` - Minimum number of shares required to reconstruct the secret.
+///
+/// ## Returns
+/// * Updated shares if successful; otherwise, an error.
+///
+/// ## Errors
+/// * Returns an error if shares are inconsistent or invalid.
+/// * Returns [`Error::CheckedRefreshRequired`] if the shares were split in checked mode,
+///   which must be refreshed with [`refresh_checked`] instead.
+#[cfg(all(feature = "refresh", feature = "std"))]
+pub fn refresh_shares(shares: &[Share], threshold: usize) -> Result<Vec<Share>, Error> {
+    refresh_shares_with_rng(shares, threshold, &mut rand::thread_rng())
+}
+
+/// Generates update keys and refreshes the shares, drawing randomness from the provided generator.
+///
+/// The refreshed shares move to the next epoch, and can no longer be combined with the shares
+/// they were refreshed from. Shares in the legacy byte layout carry no epoch.
+/// Shares of [`split_checked_with_rng`] must be refreshed with [`refresh_checked_with_rng`] instead,
+/// since this refresh changes their digest share. They are rejected, unless they were converted
+/// into the legacy byte layout, which does not record the checked mode.
+///
+/// ## Arguments
+/// * `shares` - Current shares to be refreshed
+/// * `threshold` - Minimum number of shares required to reconstruct the secret.
+/// * `rng` - A cryptographically secure random number generator.
 ///
 /// ## Returns
 /// * Updated shares if successful; otherwise, an error.
 ///
 /// ## Errors
 /// * Returns an error if shares are inconsistent or invalid.
+/// * Returns [`Error::CheckedRefreshRequired`] if the shares were split in checked mode.
 #[cfg(feature = "refresh")]
-pub fn refresh(shares: &[Vec<u8>], threshold: usize) -> Result<Vec<Vec<u8>>, Box<dyn std::error::Error>> {
+pub fn refresh_shares_with_rng<R: RngCore + CryptoRng>(
+    shares: &[Share],
+    threshold: usize,
+    rng: &mut R,
+) -> Result<Vec<Share>, Error> {
     // Validate inputs
     let parts = shares.len();
-    if parts < threshold || parts > 255 || !(2..=255).contains(&threshold) {
-        return Err("invalid input parameters".into());
+    validate_parameters(parts, threshold, MAX_PARTS)?;
+    // The threshold of the refresh polynomials must match the threshold of the split.
+    validate_threshold(shares, threshold)?;
+    // The digest share of checked shares is only preserved by `refresh_checked`.
+    if shares[0].is_checked() {
+        return Err(Error::CheckedRefreshRequired);
     }
 
-    let share_size = shares[0].len();
-    if !shares.iter().all(|share| share.len() == share_size) {
-        return Err("inconsistent share lengths".into());
-    }
+    Ok(refresh_preserving(shares, threshold, &[], rng))
+}
 
-    // The size of the data payload in each share, excluding the x-coordinate.
-    let data_size = share_size - 1;
+/// Refreshes validated shares with random polynomials that vanish at `x = 0` and at every x-coordinate
+/// of `roots`, so the secret and the points of the split at these x-coordinates are preserved.
+///
+/// ## Arguments
+/// * `shares` - Current shares to be refreshed, validated against the `threshold`.
+/// * `threshold` - Minimum number of shares required to reconstruct the secret.
+/// * `roots` - The non-zero x-coordinates, besides `x = 0`, at which the split must not change.
+/// * `rng` - A cryptographically secure random number generator.
+///
+/// ## Returns
+/// * The refreshed shares, moved to the next epoch.
+///
+/// ## Panics
+/// * If `roots` leave no random coefficient, i.e. `roots.len() + 1 >= threshold`.
+#[cfg(feature = "refresh")]
+fn refresh_preserving<R: RngCore + CryptoRng>(
+    shares: &[Share],
+    threshold: usize,
+    roots: &[u8],
+    rng: &mut R,
+) -> Vec<Share> {
+    let data_size = shares[0].payload_len();
 
     // Create new shares with same dimensions
     let mut new_shares = shares.to_vec();
 
-    // For a polynomial of degree `k−1`, you need `k` distinct points to uniquely determine it,
-    // therefor we generate a polynomial of degree `threshold - 1`.
-    let degree = (threshold - 1) as u8;
-
-    for b_idx in 0..(data_size) {
-        // Create random polynomial with f(0) = 0 to maintain the original secret
-        let refresh_polynomial = Polynomial::generate(0, degree);
-
-        // Update each share's byte at this position
-        for (s_idx, share) in new_shares.iter_mut().enumerate() {
-            // Get this share's x-coordinate (stored in last byte)
-            let x_coordinate = shares[s_idx][data_size];
-            // Calculate refresh value for this share at its x-coordinate
-            let refresh_value = refresh_polynomial.evaluate(x_coordinate);
-            // Add refresh value to share (GF(2^8) addition)
-            share[b_idx] = ops::add(share[b_idx], refresh_value);
+    // For a polynomial of degree `k−1`, you need `k` distinct points to uniquely determine it.
+    // The refresh polynomials are the products `x * (x - r1) * .. * q(x)` of their roots and random
+    // polynomials `q`, whose remaining coefficients are laid out column-wise as in `split`.
+    let coefficients = threshold - 1 - roots.len();
+    assert!(coefficients > 0, "no random coefficients left");
+    let chunk_len = SPLIT_CHUNK_LEN.min(data_size);
+    let mut columns = vec![0u8; coefficients * chunk_len];
+    let mut refresh_values = vec![0u8; chunk_len];
+
+    for offset in (0..data_size).step_by(SPLIT_CHUNK_LEN) {
+        let len = chunk_len.min(data_size - offset);
+        let columns = &mut columns[..coefficients * len];
+        rng.fill(columns);
+
+        // Update each share's bytes in this chunk
+        for share in new_shares.iter_mut() {
+            // Calculate refresh values for this share at its x-coordinate
+            let refresh_values = &mut refresh_values[..len];
+            polynomial::evaluate_columns(columns, share.x(), refresh_values);
+            // Multiply by the roots, which are the same for all bytes, and add the refresh values to the share.
+            let factor = roots
+                .iter()
+                .fold(share.x(), |factor, &root| ops::mult(factor, ops::add(share.x(), root)));
+            ops::mult_accumulate_slice(&mut share.y_mut()[offset..offset + len], factor, refresh_values);
+        }
+    }
+
+    // Clear memory associated with the refresh polynomials.
+    columns.zeroize();
+    refresh_values.zeroize();
+
+    // The refreshed shares move to the next epoch, whose transcript hash chains a random nonce of this refresh.
+    if let Some(header) = shares[0].header() {
+        let nonce: [u8; NONCE_LEN] = rng.gen();
+        let header = header.next_epoch([(0, &nonce)]);
+        for share in new_shares.iter_mut() {
+            share.set_header(Some(header));
         }
     }
 
-    Ok(new_shares)
+    new_shares
+}
+
+/// Generates update keys and refreshes the shares in the legacy byte layout `(y1, y2, .., yn, x)`.
+///
+/// This is a compatibility wrapper over [`refresh_shares`].
+///
+/// The legacy byte layout carries no epoch, therefore the refreshed shares are not protected from being
+/// mixed with the stale shares they were refreshed from, which [`combine`] turns into an incorrect secret
+/// without an error. New code should use [`refresh_shares`], whose shares reject such a mix in [`combine_shares`].
+///
+/// ## Arguments
+/// * `shares` - Current shares to be refreshed
+/// * `threshold` - Minimum number of shares required to reconstruct the secret.
+///
+/// ## Returns
+/// * Updated shares if successful; otherwise, an error.
+///
+/// ## Errors
+/// * Returns an error if shares are inconsistent or invalid.
+#[cfg(all(feature = "refresh", feature = "std"))]
+pub fn refresh(shares: &[Vec<u8>], threshold: usize) -> Result<Vec<Vec<u8>>, Error> {
+    refresh_with_rng(shares, threshold, &mut rand::thread_rng())
+}
+
+/// Generates update keys and refreshes the shares in the legacy byte layout `(y1, y2, .., yn, x)`,
+/// drawing randomness from the provided generator.
+///
+/// This is a compatibility wrapper over [`refresh_shares_with_rng`].
+///
+/// As for `refresh`, the shares carry no epoch and are not protected against being mixed with stale shares.
+/// New code should use [`refresh_shares_with_rng`] instead.
+///
+/// ## Arguments
+/// * `shares` - Current shares to be refreshed
+/// * `threshold` - Minimum number of shares required to reconstruct the secret.
+/// * `rng` - A cryptographically secure random number generator.
+///
+/// ## Returns
+/// * Updated shares if successful; otherwise, an error.
+///
+/// ## Errors
+/// * Returns an error if shares are inconsistent or invalid.
+#[cfg(feature = "refresh")]
+pub fn refresh_with_rng<R: RngCore + CryptoRng>(
+    shares: &[Vec<u8>],
+    threshold: usize,
+    rng: &mut R,
+) -> Result<Vec<Vec<u8>>, Error> {
+    let shares = refresh_shares_with_rng(&from_legacy(shares)?, threshold, rng)?;
+    Ok(shares.into_iter().map(Vec::from).collect())
 }
 
 /// Combines shares to reconstruct the secret.
 ///
 /// ## Arguments
-/// * `parts` - Shares of the secret.
+/// * `shares` - Shares of the secret.
 ///
 /// ## Returns
 /// * The original secret if successful; otherwise, an error.
 ///
 /// ## Errors
 /// * Returns an error if shares are inconsistent or insufficient.
-pub fn combine(shares: &[Vec<u8>]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
-    // Validate the parts for consistency and sufficiency.
-    if shares.len() < 2 || shares[0].len() < 2 {
-        return Err("invalid parts".into());
-    }
-
-    // Ensure all parts are of the same length.
-    let first_part_len = shares[0].len();
-    for part in shares.iter().skip(1) {
-        if part.len() != first_part_len {
-            return Err("all parts must be the same length".into());
-        }
-    }
+pub fn combine_shares(shares: &[Share]) -> Result<Vec<u8>, Error> {
+    // The secret is the constant term of each polynomial, located at `x = 0`.
+    reconstruct(shares, 0)
+}
 
-    // Initialize vectors to store the secret and the x and y samples.
-    let mut secret = vec![0u8; first_part_len - 1];
+/// Collects the x-coordinates of the shares, ensuring that they are unique.
+fn x_samples(shares: &[Share]) -> Result<Vec<u8>, Error> {
     let mut x_samples = vec![0u8; shares.len()];
-    let mut y_samples = vec![0u8; shares.len()];
-
-    // Ensure that the x-coordinates are unique.
-    let mut check_set = std::collections::HashSet::new();
-    for (idx, part) in shares.iter().enumerate() {
-        let sample = part[first_part_len - 1];
-        if check_set.contains(&sample) {
-            return Err("duplicate part detected".into());
+    unique_x_samples(shares.iter().map(Share::x), &mut x_samples)?;
+    Ok(x_samples)
+}
+
+/// Writes the x-coordinates into `x_samples`, ensuring that they are unique.
+///
+/// A duplicate is always found before more than 256 x-coordinates are written,
+/// therefore a buffer of 256 elements is sufficient for any number of x-coordinates.
+fn unique_x_samples(x_coordinates: impl Iterator<Item = u8>, x_samples: &mut [u8]) -> Result<(), Error> {
+    // Every element of `GF(2^8)` is tracked in a fixed table, which requires no hashing.
+    let mut seen = [false; 256];
+    for (idx, sample) in x_coordinates.enumerate() {
+        if seen[sample as usize] {
+            return Err(Error::DuplicateXCoordinate { index: idx });
         }
-        check_set.insert(sample);
+        seen[sample as usize] = true;
         x_samples[idx] = sample;
     }
 
-    // Reconstruct each byte of the secret using polynomial interpolation.
-    for idx in 0..(first_part_len - 1) {
-        for (i, part) in shares.iter().enumerate() {
-            y_samples[i] = part[idx];
+    Ok(())
+}
+
+/// Validates that the 16-bit x-coordinates of the shares over wider fields are non-zero and unique.
+///
+/// Every x-coordinate is tracked in a bitmap of `2^16` bits, which requires no hashing.
+fn unique_wide_x_coordinates(x_coordinates: impl Iterator<Item = u16>) -> Result<(), Error> {
+    let mut seen = vec![0u64; (1 << 16) / 64];
+    for (index, x) in x_coordinates.enumerate() {
+        if x == 0 {
+            return Err(Error::ZeroXCoordinate { index });
+        }
+        let (word, bit) = (x as usize / 64, 1u64 << (x % 64));
+        if seen[word] & bit != 0 {
+            return Err(Error::DuplicateXCoordinate { index });
         }
-        let val = Polynomial::interpolate(&x_samples, &y_samples, 0);
-        secret[idx] = val;
+        seen[word] |= bit;
+    }
+
+    Ok(())
+}
+
+/// Validates the shares and computes the Lagrange weights of their x-coordinates at the point `x`.
+///
+/// ## Arguments
+/// * `shares` - Shares of the secret, whose y-coordinates are not required.
+/// * `x` - The x-coordinate at which the polynomials are interpolated.
+///
+/// ## Returns
+/// * The weight of every share if successful; otherwise, an error.
+fn lagrange_weights(shares: &[Share], x: u8) -> Result<Vec<u8>, Error> {
+    // Validate the shares for consistency and sufficiency.
+    let required = validate_shares(shares)?;
+    if shares.len() < required {
+        return Err(Error::TooFewShares {
+            provided: shares.len(),
+            required,
+        });
     }
+    let x_samples = x_samples(shares)?;
+
+    // The Lagrange weights depend only on the x-coordinates, therefore they are computed once for all bytes.
+    let mut weights = vec![0u8; shares.len()];
+    polynomial::lagrange_weights(&x_samples, x, &mut weights);
+
+    Ok(weights)
+}
+
+/// Validates the shares and interpolates the polynomials of every byte at the point `x`.
+///
+/// ## Arguments
+/// * `shares` - Shares of the secret.
+/// * `x` - The x-coordinate at which the polynomials are interpolated.
+///
+/// ## Returns
+/// * The interpolated values if successful; otherwise, an error.
+fn reconstruct(shares: &[Share], x: u8) -> Result<Vec<u8>, Error> {
+    let weights = lagrange_weights(shares, x)?;
+    let mut secret = vec![0u8; shares[0].payload_len()];
+
+    // Reconstruct every byte of the secret as the weighted sum of the y-coordinates,
+    // share by share within independent chunks of the secret.
+    for_each_chunk(secret.chunks_mut(SPLIT_CHUNK_LEN).enumerate(), |(c_idx, secret)| {
+        let offset = c_idx * SPLIT_CHUNK_LEN;
+        for (share, &weight) in shares.iter().zip(weights.iter()) {
+            ops::mult_accumulate_slice(secret, weight, &share.y()[offset..offset + secret.len()]);
+        }
+    });
 
     Ok(secret)
 }
 
+/// Combines shares in the legacy byte layout `(y1, y2, .., yn, x)` to reconstruct the secret.
+///
+/// This is a compatibility wrapper over [`combine_shares`].
+///
+/// Shares in the legacy byte layout carry no metadata, therefore fewer shares than the threshold,
+/// or shares of different splits or refresh epochs, are combined into an incorrect secret without
+/// an error. Use [`combine_shares`] with shares in the binary format of [`Share::to_bytes`] to detect them.
+///
+/// ## Arguments
+/// * `shares` - Shares of the secret.
+///
+/// ## Returns
+/// * The original secret if successful; otherwise, an error.
+///
+/// ## Errors
+/// * Returns an error if shares are inconsistent or insufficient.
+pub fn combine(shares: &[Vec<u8>]) -> Result<Vec<u8>, Error> {
+    combine_shares(&from_legacy(shares)?)
+}
+
 // Test cases for the `lib` module.
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use super::*;
 
@@ -259,7 +826,6 @@ mod tests {
     // Test basic refresh functionality
     #[test]
     #[cfg(feature = "refresh")]
-
     fn it_refreshes_shares() {
         let secret = b"test_secret";
         let threshold = 3;
@@ -271,12 +837,37 @@ mod tests {
         let selected_shares = &refreshed_shares[..threshold];
         let reconstructed = combine(selected_shares).expect("combine failed");
         assert_eq!(reconstructed, secret);
+
+        // The legacy shares carry no epoch, therefore mixing refreshed and stale shares goes undetected.
+        let mixed = vec![
+            refreshed_shares[0].clone(),
+            refreshed_shares[1].clone(),
+            shares[2].clone(),
+        ];
+        let reconstructed = combine(&mixed).expect("combine failed");
+        assert_ne!(reconstructed, secret);
+
+        // The same mix of shares in the binary format is rejected.
+        let shares = split_shares(secret, 5, threshold).expect("split failed");
+        let refreshed_shares = refresh_shares(&shares, threshold).expect("refresh failed");
+        let mixed = vec![
+            refreshed_shares[0].clone(),
+            refreshed_shares[1].clone(),
+            shares[2].clone(),
+        ];
+        assert_eq!(
+            combine_shares(&mixed),
+            Err(Error::EpochMismatch {
+                index: 2,
+                expected: 1,
+                found: 0
+            })
+        );
     }
 
     // Test refresh with known shares
     #[test]
     #[cfg(feature = "refresh")]
-
     fn it_refreshes_known_shares() {
         // Valid known shares
         let shares = vec![
@@ -294,7 +885,6 @@ mod tests {
     // Test refresh with invalid inputs
     #[test]
     #[cfg(feature = "refresh")]
-
     fn it_fails_to_refresh_invalid_shares() {
         // Inconsistent shares
         let shares = vec![vec![1, 2], vec![3, 4, 3]];
@@ -308,4 +898,313 @@ mod tests {
         let shares = vec![vec![1, 2], vec![3, 4]];
         assert!(refresh(&shares, 3).is_err());
     }
+
+    // The 'split' function reports the cause of invalid parameters.
+    #[test]
+    fn it_reports_split_errors() {
+        let secret = b"test_secret";
+
+        assert_eq!(split(secret, 5, 1), Err(Error::ThresholdTooSmall { threshold: 1 }));
+        assert_eq!(
+            split(secret, 256, 3),
+            Err(Error::PartsExceedField { parts: 256, max: 255 })
+        );
+        assert_eq!(
+            split(secret, 2, 3),
+            Err(Error::ThresholdExceedsParts { threshold: 3, parts: 2 })
+        );
+        assert_eq!(split(&[], 5, 3), Err(Error::EmptySecret));
+    }
+
+    // The 'combine' function reports the cause of invalid shares.
+    #[test]
+    fn it_reports_combine_errors() {
+        let shares = vec![vec![1, 2]];
+        assert_eq!(
+            combine(&shares),
+            Err(Error::TooFewShares {
+                provided: 1,
+                required: 2
+            })
+        );
+
+        let shares = vec![vec![1], vec![2]];
+        assert_eq!(combine(&shares), Err(Error::ShareTooShort { index: 0, len: 1 }));
+
+        let shares = vec![vec![1, 2], vec![3, 4], vec![5, 6, 7]];
+        assert_eq!(
+            combine(&shares),
+            Err(Error::InconsistentShareLength {
+                index: 2,
+                expected: 1,
+                found: 2
+            })
+        );
+
+        let shares = vec![vec![1, 2], vec![3, 4], vec![5, 2]];
+        assert_eq!(combine(&shares), Err(Error::DuplicateXCoordinate { index: 2 }));
+    }
+
+    // The 'refresh' function reports the cause of invalid shares.
+    #[test]
+    #[cfg(feature = "refresh")]
+    fn it_reports_refresh_errors() {
+        let shares = vec![vec![1, 2], vec![3, 4]];
+        assert_eq!(refresh(&shares, 1), Err(Error::ThresholdTooSmall { threshold: 1 }));
+        assert_eq!(
+            refresh(&shares, 3),
+            Err(Error::ThresholdExceedsParts { threshold: 3, parts: 2 })
+        );
+
+        let shares = vec![vec![1], vec![2]];
+        assert_eq!(refresh(&shares, 2), Err(Error::ShareTooShort { index: 0, len: 1 }));
+
+        let shares = vec![vec![1, 2], vec![3, 4, 3]];
+        assert_eq!(
+            refresh(&shares, 2),
+            Err(Error::InconsistentShareLength {
+                index: 1,
+                expected: 1,
+                found: 2
+            })
+        );
+
+        let shares = vec![vec![1, 2]; 256];
+        assert_eq!(
+            refresh(&shares, 2),
+            Err(Error::PartsExceedField { parts: 256, max: 255 })
+        );
+    }
+
+    // The 'split_shares' and 'combine_shares' functions with the `Share` type.
+    #[test]
+    fn it_combines_from_split_shares() {
+        let secret = b"test_secret";
+        let threshold = 3;
+
+        let shares = split_shares(secret, 5, threshold).expect("split failed");
+        for share in shares.iter() {
+            assert_eq!(share.payload_len(), secret.len());
+        }
+
+        let reconstructed = combine_shares(&shares[2..]).expect("combine failed");
+        assert_eq!(reconstructed, secret);
+    }
+
+    // The legacy byte layout and the `Share` type are interchangeable.
+    #[test]
+    fn it_combines_legacy_shares_as_share_type() {
+        let shares = vec![
+            vec![137, 206, 171, 244, 28, 176, 109, 4, 12, 168, 87, 50],
+            vec![162, 176, 148, 45, 83, 38, 153, 204, 80, 141, 4, 1],
+            vec![35, 165, 19, 114, 53, 31, 70, 25, 74, 248, 145, 132],
+        ];
+        let shares: Vec<Share> = shares
+            .into_iter()
+            .map(Share::try_from)
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        let reconstructed = combine_shares(&shares).expect("combine failed");
+        assert_eq!(reconstructed, b"test_secret");
+    }
+
+    // The 'refresh_shares' function with the `Share` type preserves the x-coordinates and increments the epoch.
+    #[test]
+    #[cfg(feature = "refresh")]
+    fn it_refreshes_share_type() {
+        let secret = b"test_secret";
+        let threshold = 3;
+        let shares = split_shares(secret, 5, threshold).expect("split failed");
+        assert!(shares.iter().all(|share| share.epoch() == Some(0)));
+
+        let refreshed = refresh_shares(&shares, threshold).expect("refresh failed");
+        for (share, refreshed) in shares.iter().zip(refreshed.iter()) {
+            assert_eq!(share.x(), refreshed.x());
+            assert_eq!(refreshed.epoch(), Some(1));
+        }
+        assert!(refreshed
+            .iter()
+            .all(|share| share.transcript() == refreshed[0].transcript()));
+
+        let reconstructed = combine_shares(&refreshed[1..4]).expect("combine failed");
+        assert_eq!(reconstructed, secret);
+
+        // Refreshed shares are encoded with their epoch and transcript hash.
+        let bytes = refreshed[0].to_bytes();
+        assert_eq!(bytes[0], EPOCH_FORMAT_VERSION);
+        assert_eq!(Share::from_bytes(&bytes), Ok(refreshed[0].clone()));
+
+        let refreshed = refresh_shares(&refreshed, threshold).expect("refresh failed");
+        assert_eq!(refreshed[0].epoch(), Some(2));
+        let reconstructed = combine_shares(&refreshed[..3]).expect("combine failed");
+        assert_eq!(reconstructed, secret);
+    }
+
+    // The 'combine_shares' function rejects stale shares mixed with refreshed shares,
+    // and shares of two different refreshes of the same epoch.
+    #[test]
+    #[cfg(feature = "refresh")]
+    fn it_fails_to_combine_mixed_epochs() {
+        let shares = split_shares(b"test_secret", 5, 3).expect("split failed");
+        let refreshed = refresh_shares(&shares, 3).expect("refresh failed");
+
+        let mixed = [refreshed[0].clone(), refreshed[1].clone(), shares[2].clone()];
+        let err = combine_shares(&mixed).unwrap_err();
+        assert_eq!(
+            err,
+            Error::EpochMismatch {
+                index: 2,
+                expected: 1,
+                found: 0
+            }
+        );
+        assert_eq!(err.to_string(), "share 2 is from refresh epoch 0, expected epoch 1");
+
+        let forked = refresh_shares(&shares, 3).expect("refresh failed");
+        let mixed = [refreshed[0].clone(), forked[1].clone(), refreshed[2].clone()];
+        assert_eq!(combine_shares(&mixed), Err(Error::TranscriptMismatch { index: 1 }));
+        assert_eq!(refresh_shares(&mixed, 3), Err(Error::TranscriptMismatch { index: 1 }));
+    }
+
+    // The 'combine_shares' function rejects fewer shares than the threshold of the split.
+    #[test]
+    fn it_fails_to_combine_below_threshold() {
+        let shares = split_shares(b"test_secret", 5, 4).expect("split failed");
+
+        let err = combine_shares(&shares[..2]).unwrap_err();
+        assert_eq!(
+            err,
+            Error::TooFewShares {
+                provided: 2,
+                required: 4
+            }
+        );
+        assert_eq!(err.to_string(), "need 2 more shares (2 of 4)");
+    }
+
+    // The 'combine_shares' function rejects shares from different splits.
+    #[test]
+    fn it_fails_to_combine_mixed_splits() {
+        let first = split_shares(b"test_secret", 3, 2).expect("split failed");
+        let second = split_shares(b"test_secret", 3, 2).expect("split failed");
+        // Guard against the unlikely case of colliding identifiers.
+        assert_ne!(first[0].id(), second[0].id());
+
+        let mixed = vec![first[0].clone(), first[1].clone(), second[2].clone()];
+        assert_eq!(combine_shares(&mixed), Err(Error::SplitIdMismatch { index: 2 }));
+
+        // Shares without metadata cannot be mixed with shares of a split.
+        let legacy = Share::try_from(Vec::from(second[1].clone())).expect("conversion failed");
+        let mixed = vec![first[0].clone(), legacy];
+        assert_eq!(combine_shares(&mixed), Err(Error::SplitIdMismatch { index: 1 }));
+    }
+
+    // Shares survive a round-trip through the versioned binary format.
+    #[test]
+    fn it_combines_decoded_shares() {
+        let secret = b"test_secret";
+        let shares = split_shares(secret, 5, 3).expect("split failed");
+
+        let decoded: Vec<Share> = shares[1..4]
+            .iter()
+            .map(|share| Share::from_bytes(&share.to_bytes()))
+            .collect::<Result<_, _>>()
+            .expect("decoding failed");
+        assert_eq!(decoded[0].threshold(), Some(3));
+
+        let reconstructed = combine_shares(&decoded).expect("combine failed");
+        assert_eq!(reconstructed, secret);
+    }
+
+    // The 'refresh_shares' function rejects a threshold different from the split.
+    #[test]
+    #[cfg(feature = "refresh")]
+    fn it_fails_to_refresh_with_different_threshold() {
+        let shares = split_shares(b"test_secret", 5, 3).expect("split failed");
+        assert_eq!(
+            refresh_shares(&shares, 4),
+            Err(Error::ThresholdMismatch {
+                index: 0,
+                expected: 3,
+                found: 4
+            })
+        );
+    }
+
+    // The '_with_rng' functions are reproducible with a seeded generator.
+    #[test]
+    fn it_splits_with_seeded_rng() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let secret = b"test_secret";
+        let first = split_with_rng(secret, 5, 3, &mut StdRng::seed_from_u64(42)).expect("split failed");
+        let second = split_with_rng(secret, 5, 3, &mut StdRng::seed_from_u64(42)).expect("split failed");
+        assert_eq!(first, second);
+
+        let shares = split_shares_with_rng(secret, 5, 3, &mut StdRng::seed_from_u64(42)).expect("split failed");
+        let third = split_shares_with_rng(secret, 5, 3, &mut StdRng::seed_from_u64(42)).expect("split failed");
+        assert_eq!(shares, third);
+        assert_eq!(combine_shares(&shares[..3]).expect("combine failed"), secret);
+    }
+
+    // The 'refresh_with_rng' function is reproducible with a seeded generator.
+    #[test]
+    #[cfg(feature = "refresh")]
+    fn it_refreshes_with_seeded_rng() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let shares = split(b"test_secret", 5, 3).expect("split failed");
+        let first = refresh_with_rng(&shares, 3, &mut StdRng::seed_from_u64(42)).expect("refresh failed");
+        let second = refresh_with_rng(&shares, 3, &mut StdRng::seed_from_u64(42)).expect("refresh failed");
+
+        assert_eq!(first, second);
+        assert_eq!(combine(&first[2..]).expect("combine failed"), b"test_secret");
+    }
+
+    // Secrets spanning multiple chunks of coefficients are split and refreshed correctly.
+    #[test]
+    #[cfg(feature = "refresh")]
+    fn it_splits_and_refreshes_large_secrets() {
+        let secret: Vec<u8> = (0..2 * SPLIT_CHUNK_LEN + 17).map(|i| (i * 7) as u8).collect();
+
+        let shares = split_shares(&secret, 5, 3).expect("split failed");
+        assert_eq!(combine_shares(&shares[2..]).expect("combine failed"), secret);
+
+        let refreshed = refresh_shares(&shares, 3).expect("refresh failed");
+        assert_eq!(combine_shares(&refreshed[..3]).expect("combine failed"), secret);
+        assert_ne!(refreshed[0].y(), shares[0].y());
+    }
+
+    // The shares of secrets spanning multiple batches of chunks only depend on the generator,
+    // which draws the coefficients of every chunk in the order of the secret.
+    #[test]
+    fn it_splits_batches_deterministically() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let secret: Vec<u8> = (0..2 * SPLIT_BATCH_CHUNKS * SPLIT_CHUNK_LEN + 100)
+            .map(|i| (i * 13) as u8)
+            .collect();
+        let shares = split_shares_with_rng(&secret, 4, 3, &mut StdRng::seed_from_u64(7)).expect("split failed");
+        assert_eq!(combine_shares(&shares[1..]).expect("combine failed"), secret);
+
+        // Replay the generator and evaluate the polynomial of every byte on its own.
+        let mut rng = StdRng::seed_from_u64(7);
+        let x_coordinates = shuffled_x_coordinates(MAX_PARTS as u8, &mut rng);
+        let _id: u32 = rng.gen();
+        for (c_idx, chunk) in secret.chunks(SPLIT_CHUNK_LEN).enumerate() {
+            let mut columns = vec![0u8; 3 * chunk.len()];
+            columns[..chunk.len()].copy_from_slice(chunk);
+            rng.fill(&mut columns[chunk.len()..]);
+
+            for (share, &x) in shares.iter().zip(x_coordinates.iter()) {
+                assert_eq!(share.x(), x);
+                for i in 0..chunk.len() {
+                    let coefficients: Vec<u8> = columns.iter().skip(i).step_by(chunk.len()).copied().collect();
+                    let expected = polynomial::evaluate_coefficients(&coefficients, x);
+                    assert_eq!(share.y()[c_idx * SPLIT_CHUNK_LEN + i], expected);
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,393 @@
+use crate::{combine_shares, split_shares_with_rng, Error, Share};
+use alloc::vec::Vec;
+use rand::{CryptoRng, Rng, RngCore};
+use sha2::{Digest, Sha256};
+use subtle::ConstantTimeEq;
+use zeroize::Zeroize;
+
+/// The version of the binary format of manifests written by [`Manifest::to_bytes`].
+pub const MANIFEST_FORMAT_VERSION: u8 = 0x88;
+
+/// The number of random bytes salting every commitment.
+const SALT_LEN: usize = 16;
+
+/// The length of a commitment: its salt followed by the SHA-256 digest.
+const COMMITMENT_LEN: usize = SALT_LEN + 32;
+
+/// The length of the header in the binary format of manifests: version (1 byte), threshold (1 byte),
+/// split identifier (4 bytes), number of shares (1 byte) and the commitment to the secret.
+const MANIFEST_HEADER_LEN: usize = 7 + COMMITMENT_LEN;
+
+/// The tags hashed in front of the committed values, which separate the commitments to shares
+/// from the commitment to the secret.
+const SHARE_TAG: &[u8] = b"shamirs manifest share";
+const SECRET_TAG: &[u8] = b"shamirs manifest secret";
+
+/// A salted SHA-256 commitment `SHA-256(tag || salt || value)` to a share or to the secret.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Commitment {
+    /// The random salt, which prevents the comparison of commitments across splits.
+    salt: [u8; SALT_LEN],
+    /// The digest of the tag, the salt and the committed value.
+    digest: [u8; 32],
+}
+
+impl Commitment {
+    /// Commits to `value` with a salt drawn from the provided generator.
+    fn new<R: RngCore + CryptoRng>(tag: &[u8], value: &[u8], rng: &mut R) -> Commitment {
+        let salt = rng.gen();
+        Commitment {
+            salt,
+            digest: Commitment::digest(tag, &salt, value),
+        }
+    }
+
+    /// Computes the digest of the tag, the salt and the committed value.
+    fn digest(tag: &[u8], salt: &[u8; SALT_LEN], value: &[u8]) -> [u8; 32] {
+        Sha256::new()
+            .chain_update(tag)
+            .chain_update(salt)
+            .chain_update(value)
+            .finalize()
+            .into()
+    }
+
+    /// Checks in constant-time whether `value` matches the commitment.
+    fn opens_to(&self, tag: &[u8], value: &[u8]) -> bool {
+        Commitment::digest(tag, &self.salt, value).ct_eq(&self.digest).into()
+    }
+
+    /// Parses a commitment from the first [`COMMITMENT_LEN`] bytes.
+    fn decode(bytes: &[u8]) -> Commitment {
+        Commitment {
+            salt: bytes[..SALT_LEN].try_into().expect("salt length"),
+            digest: bytes[SALT_LEN..COMMITMENT_LEN].try_into().expect("digest length"),
+        }
+    }
+
+    /// Appends the salt and the digest to `bytes`.
+    fn encode(&self, bytes: &mut Vec<u8>) {
+        bytes.extend_from_slice(&self.salt);
+        bytes.extend_from_slice(&self.digest);
+    }
+}
+
+/// The public manifest of a split, with a salted SHA-256 commitment to every share and to the secret.
+///
+/// The manifest lets each holder verify its own share with [`Manifest::verify_share`], and
+/// [`combine_committed`] verify every input share before interpolating, without any dependency
+/// on elliptic curves. Each commitment covers the whole binary format of [`Share::to_bytes`],
+/// so substituted, corrupted or relabelled shares are detected at the byte level.
+///
+/// The salts are published along with the digests, therefore the commitment to the secret can be
+/// tested against guesses, and the mode is only hiding for secrets with high entropy, such as keys.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Manifest {
+    /// Minimum number of shares required to reconstruct the secret.
+    threshold: u8,
+    /// Random identifier shared by the shares of the same split.
+    id: u32,
+    /// The commitment to the secret.
+    secret: Commitment,
+    /// The commitments to the shares, with the x-coordinate of each share.
+    shares: Vec<(u8, Commitment)>,
+}
+
+impl Manifest {
+    /// Parses a manifest from its binary format.
+    ///
+    /// ## Arguments
+    /// * `bytes` - The encoded manifest, as written by [`Manifest::to_bytes`].
+    ///
+    /// ## Returns
+    /// * The decoded manifest if successful; otherwise, an error.
+    ///
+    /// ## Errors
+    /// * Returns an error if the format version is unsupported, the manifest is truncated,
+    ///   or it declares an invalid threshold or duplicate x-coordinates.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Manifest, Error> {
+        let version = *bytes.first().ok_or(Error::ShareTooShort { index: 0, len: 0 })?;
+        if version != MANIFEST_FORMAT_VERSION {
+            return Err(Error::UnsupportedVersion { version });
+        }
+        if bytes.len() < MANIFEST_HEADER_LEN {
+            return Err(Error::InvalidBufferLength {
+                index: 0,
+                expected: MANIFEST_HEADER_LEN,
+                found: bytes.len(),
+            });
+        }
+        let parts = bytes[6] as usize;
+        if bytes.len() != MANIFEST_HEADER_LEN + parts * (1 + COMMITMENT_LEN) {
+            return Err(Error::InvalidBufferLength {
+                index: 0,
+                expected: MANIFEST_HEADER_LEN + parts * (1 + COMMITMENT_LEN),
+                found: bytes.len(),
+            });
+        }
+        let threshold = bytes[1];
+        if threshold < 2 {
+            return Err(Error::ThresholdTooSmall {
+                threshold: threshold as usize,
+            });
+        }
+
+        let mut seen = [false; 256];
+        let mut shares = Vec::with_capacity(parts);
+        for (index, entry) in bytes[MANIFEST_HEADER_LEN..]
+            .chunks_exact(1 + COMMITMENT_LEN)
+            .enumerate()
+        {
+            if seen[entry[0] as usize] {
+                return Err(Error::DuplicateXCoordinate { index });
+            }
+            seen[entry[0] as usize] = true;
+            shares.push((entry[0], Commitment::decode(&entry[1..])));
+        }
+
+        Ok(Manifest {
+            threshold,
+            id: u32::from_be_bytes([bytes[2], bytes[3], bytes[4], bytes[5]]),
+            secret: Commitment::decode(&bytes[7..]),
+            shares,
+        })
+    }
+
+    /// Encodes the manifest into the binary format `(version, threshold, id, parts, secret, shares)`,
+    /// with the split identifier in big-endian, every commitment as its salt followed by its digest,
+    /// and every commitment to a share preceded by the x-coordinate of the share.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(MANIFEST_HEADER_LEN + self.shares.len() * (1 + COMMITMENT_LEN));
+        bytes.push(MANIFEST_FORMAT_VERSION);
+        bytes.push(self.threshold);
+        bytes.extend_from_slice(&self.id.to_be_bytes());
+        bytes.push(self.shares.len() as u8);
+        self.secret.encode(&mut bytes);
+        for (x, commitment) in self.shares.iter() {
+            bytes.push(*x);
+            commitment.encode(&mut bytes);
+        }
+        bytes
+    }
+
+    /// Returns the minimum number of shares required to reconstruct the secret.
+    pub fn threshold(&self) -> usize {
+        self.threshold as usize
+    }
+
+    /// Returns the random identifier shared by the shares of the same split.
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Returns the number of shares committed to by the manifest.
+    pub fn parts(&self) -> usize {
+        self.shares.len()
+    }
+
+    /// Verifies a share against its commitment in the manifest.
+    ///
+    /// ## Arguments
+    /// * `share` - The share received from the dealer.
+    ///
+    /// ## Returns
+    /// * `Ok(())` if the share matches its commitment; otherwise, an error.
+    ///
+    /// ## Errors
+    /// * Returns [`Error::ShareVerificationFailed`] if the manifest has no commitment at the x-coordinate
+    ///   of the share, or the share does not match it.
+    pub fn verify_share(&self, share: &Share) -> Result<(), Error> {
+        let mut bytes = share.to_bytes();
+        let valid = self
+            .shares
+            .iter()
+            .find(|(x, _)| *x == share.x())
+            .is_some_and(|(_, commitment)| commitment.opens_to(SHARE_TAG, &bytes));
+        bytes.zeroize();
+
+        if valid {
+            Ok(())
+        } else {
+            Err(Error::ShareVerificationFailed { index: 0 })
+        }
+    }
+}
+
+/// The shares of a split, with the manifest published to all holders.
+type CommittedSplit = (Vec<Share>, Manifest);
+
+/// Splits a secret into multiple shares and a manifest of salted SHA-256 commitments
+/// to every share and to the secret.
+///
+/// The shares are those of [`split_shares`](crate::split_shares), and are combined and verified
+/// with [`combine_committed`].
+///
+/// ## Arguments
+/// * `secret` - The secret to be split.
+/// * `parts` - Total number of shares to create.
+/// * `threshold` - Minimum number of shares required to reconstruct the secret.
+///
+/// ## Returns
+/// * A vector of shares and the manifest if successful; otherwise, an error.
+///
+/// ## Errors
+/// * Returns an error if parameters are invalid (e.g., `parts` < `threshold`).
+#[cfg(feature = "std")]
+pub fn split_committed(secret: &[u8], parts: usize, threshold: usize) -> Result<CommittedSplit, Error> {
+    split_committed_with_rng(secret, parts, threshold, &mut rand::thread_rng())
+}
+
+/// Splits a secret into multiple shares and a manifest of salted SHA-256 commitments,
+/// drawing randomness from the provided generator.
+///
+/// ## Arguments
+/// * `secret` - The secret to be split.
+/// * `parts` - Total number of shares to create.
+/// * `threshold` - Minimum number of shares required to reconstruct the secret.
+/// * `rng` - A cryptographically secure random number generator.
+///
+/// ## Returns
+/// * A vector of shares and the manifest if successful; otherwise, an error.
+///
+/// ## Errors
+/// * Returns an error if parameters are invalid (e.g., `parts` < `threshold`).
+pub fn split_committed_with_rng<R: RngCore + CryptoRng>(
+    secret: &[u8],
+    parts: usize,
+    threshold: usize,
+    rng: &mut R,
+) -> Result<CommittedSplit, Error> {
+    let shares = split_shares_with_rng(secret, parts, threshold, rng)?;
+
+    let secret_commitment = Commitment::new(SECRET_TAG, secret, rng);
+    let share_commitments = shares
+        .iter()
+        .map(|share| {
+            let mut bytes = share.to_bytes();
+            let commitment = Commitment::new(SHARE_TAG, &bytes, rng);
+            bytes.zeroize();
+            (share.x(), commitment)
+        })
+        .collect();
+
+    let manifest = Manifest {
+        threshold: threshold as u8,
+        id: shares[0].id().expect("shares carry a header"),
+        secret: secret_commitment,
+        shares: share_commitments,
+    };
+    Ok((shares, manifest))
+}
+
+/// Combines shares after verifying each of them against the manifest,
+/// then verifies the reconstructed secret against its commitment.
+///
+/// ## Arguments
+/// * `shares` - Shares of the secret.
+/// * `manifest` - The manifest published by the dealer.
+///
+/// ## Returns
+/// * The original secret if successful; otherwise, an error.
+///
+/// ## Errors
+/// * Returns [`Error::ShareVerificationFailed`] with the index of the first share that does not match the manifest.
+/// * Returns an error if shares are inconsistent or insufficient.
+/// * Returns [`Error::IntegrityCheckFailed`] if the reconstructed secret does not match its commitment.
+pub fn combine_committed(shares: &[Share], manifest: &Manifest) -> Result<Vec<u8>, Error> {
+    for (index, share) in shares.iter().enumerate() {
+        manifest
+            .verify_share(share)
+            .map_err(|_| Error::ShareVerificationFailed { index })?;
+    }
+
+    let mut secret = combine_shares(shares)?;
+    if !manifest.secret.opens_to(SECRET_TAG, &secret) {
+        // The reconstructed bytes are not the original secret, but are cleared nonetheless.
+        secret.zeroize();
+        return Err(Error::IntegrityCheckFailed);
+    }
+
+    Ok(secret)
+}
+
+// Test cases for the hash-committed split mode.
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    // Every share of 'split_committed' is verified and the shares are combined with 'combine_committed'.
+    #[test]
+    fn it_combines_committed_shares() {
+        let secret = b"committed_secret";
+        let (shares, manifest) = split_committed(secret, 5, 3).expect("split failed");
+
+        assert_eq!((manifest.threshold(), manifest.parts()), (3, 5));
+        for share in shares.iter() {
+            assert_eq!(manifest.verify_share(share), Ok(()));
+        }
+        let reconstructed = combine_committed(&shares[1..4], &manifest).expect("combine failed");
+        assert_eq!(reconstructed, secret);
+    }
+
+    // Corrupted and substituted shares are detected before interpolating.
+    #[test]
+    fn it_rejects_uncommitted_shares() {
+        let secret = b"committed_secret";
+        let (shares, manifest) = split_committed(secret, 5, 3).expect("split failed");
+
+        let mut corrupted = shares[2].clone();
+        corrupted.y_mut()[0] ^= 0x01;
+        let selected = [shares[0].clone(), shares[1].clone(), corrupted];
+        assert_eq!(
+            combine_committed(&selected, &manifest),
+            Err(Error::ShareVerificationFailed { index: 2 })
+        );
+
+        // A share of another split of the same secret is not committed to by the manifest.
+        let (other, _) = split_committed(secret, 5, 3).expect("split failed");
+        assert_eq!(
+            manifest.verify_share(&other[0]),
+            Err(Error::ShareVerificationFailed { index: 0 })
+        );
+    }
+
+    // A manifest whose commitment to the secret was replaced is detected after interpolating.
+    #[test]
+    fn it_verifies_reconstructed_secret() {
+        let (shares, manifest) = split_committed(b"committed_secret", 3, 2).expect("split failed");
+        let (_, other) = split_committed(b"another_secret", 3, 2).expect("split failed");
+
+        let mut bytes = manifest.to_bytes();
+        bytes[7..7 + COMMITMENT_LEN].copy_from_slice(&other.to_bytes()[7..7 + COMMITMENT_LEN]);
+        let tampered = Manifest::from_bytes(&bytes).expect("decoding failed");
+        assert_eq!(combine_committed(&shares, &tampered), Err(Error::IntegrityCheckFailed));
+    }
+
+    // Encoding and decoding of the binary format of the manifest.
+    #[test]
+    fn it_encodes_manifest() {
+        let (_, manifest) = split_committed(b"committed_secret", 4, 2).expect("split failed");
+        let bytes = manifest.to_bytes();
+        assert_eq!(bytes.len(), MANIFEST_HEADER_LEN + 4 * (1 + COMMITMENT_LEN));
+        assert_eq!(Manifest::from_bytes(&bytes), Ok(manifest));
+
+        assert_eq!(
+            Manifest::from_bytes(&bytes[..bytes.len() - 1]),
+            Err(Error::InvalidBufferLength {
+                index: 0,
+                expected: bytes.len(),
+                found: bytes.len() - 1
+            })
+        );
+        let mut duplicate = bytes.clone();
+        duplicate[MANIFEST_HEADER_LEN + 1 + COMMITMENT_LEN] = duplicate[MANIFEST_HEADER_LEN];
+        assert_eq!(
+            Manifest::from_bytes(&duplicate),
+            Err(Error::DuplicateXCoordinate { index: 1 })
+        );
+        assert_eq!(
+            Manifest::from_bytes(&[0x01]),
+            Err(Error::UnsupportedVersion { version: 0x01 })
+        );
+    }
+}
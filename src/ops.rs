@@ -1,3 +1,4 @@
+use crate::backend::{self, Bitsliced, FieldBackend, Portable};
 use subtle::{ConditionallySelectable, ConstantTimeEq};
 
 /// The irreducible polynomial in `GF(2^8)`.
@@ -81,6 +82,55 @@ pub(crate) fn mult(a: u8, b: u8) -> u8 {
     product
 }
 
+/// Multiplies every element of a slice by a `scalar` and adds the corresponding element of `addend`
+/// in `GF(2^8)`, utilizing constant-time operations.
+///
+/// Computes `values[i] = values[i] * scalar + addend[i]`, which is a single step of Horner's method
+/// applied to a whole slice of polynomials at once.
+///
+/// ## Arguments
+/// * `values` - The multiplicands, replaced by the results.
+/// * `scalar` - The multiplier shared by all elements.
+/// * `addend` - The values added to the products, in the same length as `values`.
+pub(crate) fn mult_add_slice(values: &mut [u8], scalar: u8, addend: &[u8]) {
+    mult_xor_slice::<true>(values, addend, scalar);
+}
+
+/// Multiplies every element of a slice by a `scalar` and accumulates the products into `accumulator`
+/// in `GF(2^8)`, utilizing constant-time operations.
+///
+/// Computes `accumulator[i] = accumulator[i] + values[i] * scalar`, which adds a whole slice of weighted
+/// y-coordinates to the interpolated values at once.
+///
+/// ## Arguments
+/// * `accumulator` - The sums, updated with the products.
+/// * `scalar` - The multiplier shared by all elements.
+/// * `values` - The multiplicands, in the same length as `accumulator`.
+pub(crate) fn mult_accumulate_slice(accumulator: &mut [u8], scalar: u8, values: &[u8]) {
+    mult_xor_slice::<false>(accumulator, values, scalar);
+}
+
+/// Computes `values[i] = values[i] * scalar + other[i]` if `HORNER` is set,
+/// otherwise `values[i] = other[i] * scalar + values[i]`.
+///
+/// With the `simd` feature, the elements are processed by the fastest kernel supported by the CPU.
+/// On targets without vector units, the remaining whole blocks of 64 elements are processed by the
+/// bitsliced backend. The elements left are processed by the portable backend.
+#[inline(never)]
+fn mult_xor_slice<const HORNER: bool>(values: &mut [u8], other: &[u8], scalar: u8) {
+    let mut processed = 0;
+
+    #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+    if let Some(kernel) = crate::simd::Kernel::detect() {
+        processed = kernel.mult_xor::<HORNER>(values, other, scalar);
+    }
+
+    if !backend::VECTORIZED {
+        processed += Bitsliced::mult_xor::<HORNER>(&mut values[processed..], &other[processed..], scalar);
+    }
+    Portable::mult_xor::<HORNER>(&mut values[processed..], &other[processed..], scalar);
+}
+
 /// Performs addition (`XOR`) of two `u8` values in `GF(2^8)`.
 ///
 /// ## Arguments
@@ -107,7 +157,7 @@ pub(crate) fn add(a: u8, b: u8) -> u8 {
 ///
 /// ## Panics
 /// * If `a` is zero, since the inverse of zero is undefined.
-fn inverse(a: u8) -> u8 {
+pub(crate) fn inverse(a: u8) -> u8 {
     if a == 0 {
         // As a conditional, it may provide side-channel (timing) based information,
         // although the inverse of zero is undefined therefor it`s just a safe-guard
@@ -168,6 +218,24 @@ mod tests {
         assert_eq!(mult(0x52, 0xF2), 0x94);
         assert_eq!(mult(0x12, 0xAA), 0x01);
     }
+    // Tests for the `mult_add_slice` and `mult_accumulate_slice` functions against the scalar `mult` function.
+    #[test]
+    fn it_mults_slices() {
+        let values: [u8; 256] = core::array::from_fn(|i| i as u8);
+        let addend: [u8; 256] = core::array::from_fn(|i| (i as u8).wrapping_mul(31));
+
+        for scalar in 0..=255 {
+            let mut products = values;
+            mult_add_slice(&mut products, scalar, &addend);
+            let mut sums = addend;
+            mult_accumulate_slice(&mut sums, scalar, &values);
+
+            for (i, (&value, &add)) in values.iter().zip(addend.iter()).enumerate() {
+                assert_eq!(products[i], add ^ mult(value, scalar));
+                assert_eq!(sums[i], add ^ mult(value, scalar));
+            }
+        }
+    }
     // Tests for the `div` function.
     #[test]
     fn it_divs() {
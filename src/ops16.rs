@@ -0,0 +1,177 @@
+/// The irreducible polynomial `x^16 + x^12 + x^3 + x + 1` in `GF(2^16)`, without the implicit `x^16` term.
+const IRREDUCIBLE_POLYNOMIAL: u16 = 0x100B;
+
+/// Performs addition (`XOR`) of two `u16` values in `GF(2^16)`.
+///
+/// ## Arguments
+/// * `a` - First operand.
+/// * `b` - Second operand.
+///
+/// ## Returns
+/// * Result of the `XOR` operation.
+pub(crate) fn add(a: u16, b: u16) -> u16 {
+    // Addition in a finite field is equivalent to the `XOR` operation.
+    a ^ b
+}
+
+/// Performs multiplication of two `u16` values in `GF(2^16)`, utilizing constant-time operations.
+///
+/// ## Arguments
+/// * `a` - Multiplicand.
+/// * `b` - Multiplier.
+///
+/// ## Returns
+/// * Result of the multiplication.
+//
+// As in `GF(2^8)`, inlining is prevented so that the compiler cannot introduce branches
+// depending on the operands of the calling function.
+#[inline(never)]
+pub(crate) fn mult(a: u16, b: u16) -> u16 {
+    let mut product = 0u16;
+
+    for i in (0..16).rev() {
+        // The bit of `b` at the current position selects whether `a` is added.
+        let contribution = (b >> i) & 1;
+        // The MSB of `product` selects whether the doubled product is reduced.
+        let reduction = (product >> 15) & 1;
+
+        product = (product << 1) ^ (contribution * a) ^ (reduction * IRREDUCIBLE_POLYNOMIAL);
+    }
+
+    product
+}
+
+/// Computes the multiplicative inverse of a value in `GF(2^16)`.
+///
+/// The multiplicative inverse of `a` in `GF(2^16)` can be expressed as `a^65534 = a^-1`,
+/// since the multiplicative order of `GF(2^16)` is `65535`, excluding zero.
+///
+/// ## Arguments
+/// * `a` - The value to find the inverse of.
+///
+/// ## Returns
+/// * The multiplicative inverse.
+///
+/// ## Panics
+/// * If `a` is zero, since the inverse of zero is undefined.
+pub(crate) fn inverse(a: u16) -> u16 {
+    if a == 0 {
+        // As a conditional, it may provide side-channel (timing) based information,
+        // although the inverse of zero is undefined therefor it`s just a safe-guard
+        // and should never occur.
+        panic!("inverse of zero is undefined");
+    }
+
+    // Squaring and multiplying 14 times results in `b = a^(2^15 - 1)`.
+    let mut b = a;
+    for _ in 0..14 {
+        b = mult(b, b);
+        b = mult(b, a);
+    }
+
+    // finalization: b = a^65534 -> a^-1
+    mult(b, b)
+}
+
+/// Computes the multiples `scalar * 2^i` of the scalar, which determine the product with any element
+/// as the `XOR` of the multiples selected by the bits of the element.
+fn multiples(scalar: u16) -> [u16; 16] {
+    core::array::from_fn(|i| mult(scalar, 1 << i))
+}
+
+/// Computes `values[i] = values[i] * scalar + other[i]` if `HORNER` is set,
+/// otherwise `values[i] = other[i] * scalar + values[i]`.
+///
+/// The multiples are selected with masks instead of branches or table lookups indexed by secret values,
+/// which keeps the execution time independent of the elements.
+fn mult_xor_slice<const HORNER: bool>(values: &mut [u16], other: &[u16], scalar: u16) {
+    let multiples = multiples(scalar);
+
+    for (value, &other) in values.iter_mut().zip(other.iter()) {
+        let (factor, addend) = if HORNER { (*value, other) } else { (other, *value) };
+        let mut product = 0u16;
+        for (i, &multiple) in multiples.iter().enumerate() {
+            // A mask of all ones if the i-th bit of the factor is set, otherwise zero.
+            let mask = 0u16.wrapping_sub((factor >> i) & 1);
+            product ^= multiple & mask;
+        }
+        *value = product ^ addend;
+    }
+}
+
+/// Multiplies every element of a slice by a `scalar` and adds the corresponding element of `addend`
+/// in `GF(2^16)`, utilizing constant-time operations.
+///
+/// ## Arguments
+/// * `values` - The multiplicands, replaced by the results.
+/// * `scalar` - The multiplier shared by all elements.
+/// * `addend` - The values added to the products, in the same length as `values`.
+pub(crate) fn mult_add_slice(values: &mut [u16], scalar: u16, addend: &[u16]) {
+    mult_xor_slice::<true>(values, addend, scalar);
+}
+
+/// Multiplies every element of a slice by a `scalar` and accumulates the products into `accumulator`
+/// in `GF(2^16)`, utilizing constant-time operations.
+///
+/// ## Arguments
+/// * `accumulator` - The sums, updated with the products.
+/// * `scalar` - The multiplier shared by all elements.
+/// * `values` - The multiplicands, in the same length as `accumulator`.
+pub(crate) fn mult_accumulate_slice(accumulator: &mut [u16], scalar: u16, values: &[u16]) {
+    mult_xor_slice::<false>(accumulator, values, scalar);
+}
+
+// Tests for basic arithmetic operation in `GF(2^16)`.
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Tests for the `mult` function.
+    #[test]
+    fn it_mults() {
+        // With zero and one (identity element).
+        assert_eq!(mult(0x0000, 0x1234), 0x0000);
+        assert_eq!(mult(0x0001, 0x1234), 0x1234);
+        // Products below the degree of the field are not reduced.
+        assert_eq!(mult(0x0100, 0x0080), 0x8000);
+        // `x^16` is reduced to `x^12 + x^3 + x + 1`.
+        assert_eq!(mult(0x8000, 0x0002), 0x100B);
+        // Multiplication is commutative.
+        assert_eq!(mult(0xBEEF, 0x1D2C), mult(0x1D2C, 0xBEEF));
+        assert_eq!(add(0xFFFF, 0x0F0F), 0xF0F0);
+    }
+
+    // Every non-zero element has an inverse, which confirms that the polynomial is irreducible.
+    #[test]
+    fn it_inverts_every_element() {
+        for a in 1..=u16::MAX {
+            assert_eq!(mult(a, inverse(a)), 1, "{a:#06x}");
+        }
+    }
+
+    // Tests for the `mult_add_slice` and `mult_accumulate_slice` functions against the scalar `mult` function.
+    #[test]
+    fn it_mults_slices() {
+        let values: [u16; 64] = core::array::from_fn(|i| (i as u16).wrapping_mul(0x1F3D));
+        let addend: [u16; 64] = core::array::from_fn(|i| (i as u16).wrapping_mul(0x0A5B) ^ 0xC001);
+
+        for scalar in [0x0000, 0x0001, 0x0002, 0x8000, 0xBEEF, 0xFFFF] {
+            let mut products = values;
+            mult_add_slice(&mut products, scalar, &addend);
+            let mut sums = addend;
+            mult_accumulate_slice(&mut sums, scalar, &values);
+
+            for (i, (&value, &add)) in values.iter().zip(addend.iter()).enumerate() {
+                assert_eq!(products[i], add ^ mult(value, scalar));
+                assert_eq!(sums[i], add ^ mult(value, scalar));
+            }
+        }
+    }
+
+    // Test and fail computing the inverse of zero.
+    #[test]
+    #[should_panic(expected = "inverse of zero is undefined")]
+    fn it_fails_at_inverse_of_zero() {
+        inverse(0);
+    }
+}
@@ -0,0 +1,368 @@
+use crate::{
+    curve::{deal, scalar_from_u16, PrimeGroup, ScalarField, ScalarShare, SCALAR_HEADER_LEN},
+    feldman::{decode_points, encode_points, evaluate_points, validate_share},
+    polynomial::Polynomial,
+    Error,
+};
+use alloc::vec::Vec;
+use core::fmt;
+use rand::{CryptoRng, RngCore};
+
+/// The version of the binary format of Pedersen commitments written by [`PedersenCommitments::to_bytes`].
+pub const PEDERSEN_FORMAT_VERSION: u8 = 0x86;
+
+/// The version of the binary format of Pedersen shares written by [`PedersenShare::to_bytes`].
+pub const PEDERSEN_SHARE_FORMAT_VERSION: u8 = 0x87;
+
+/// The public commitments of a dealer to the coefficients of the polynomials of a secret scalar
+/// and of its blinding, which allow every holder to verify its share while hiding the secret.
+///
+/// The i-th commitment is `g^{a_i} h^{b_i}`, written additively as `a_i * G + b_i * H`, for the i-th
+/// coefficients of the secret and blinding polynomials, where `h` is the
+/// [`blinding generator`](PrimeGroup::blinding_generator). Since `b_0` is uniformly random, the commitments
+/// reveal no information about the secret, even to an adversary with unbounded computing power.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PedersenCommitments<G: PrimeGroup> {
+    /// The commitments to the coefficients, ordered from the intercept up to the highest-degree term.
+    points: Vec<G>,
+    /// Random identifier shared by the shares of the same split.
+    id: u32,
+}
+
+impl<G: PrimeGroup> PedersenCommitments<G> {
+    /// Parses the commitments from their binary format.
+    ///
+    /// ## Arguments
+    /// * `bytes` - The encoded commitments, as written by [`PedersenCommitments::to_bytes`].
+    ///
+    /// ## Returns
+    /// * The decoded commitments if successful; otherwise, an error.
+    ///
+    /// ## Errors
+    /// * Returns an error if the format version or the group is unsupported, or the commitments
+    ///   are truncated or not valid elements of the group.
+    pub fn from_bytes(bytes: &[u8]) -> Result<PedersenCommitments<G>, Error> {
+        let (id, points) = decode_points(PEDERSEN_FORMAT_VERSION, bytes)?;
+        Ok(PedersenCommitments { points, id })
+    }
+
+    /// Encodes the commitments into the binary format `(version, group, id, C_0, C_1, .., C_{t-1})`,
+    /// as for [`FeldmanCommitments`](crate::FeldmanCommitments) with its own format version.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        encode_points(PEDERSEN_FORMAT_VERSION, self.id, &self.points)
+    }
+
+    /// Returns the minimum number of shares required to reconstruct the secret.
+    pub fn threshold(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Returns the random identifier shared by the shares of the same split.
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Returns the commitments to the coefficients, ordered from the intercept up to the highest-degree term.
+    pub fn points(&self) -> &[G] {
+        &self.points
+    }
+}
+
+/// A share of Pedersen's scheme, consisting of the share `s_i` of the secret scalar
+/// and the share `t_i` of the blinding polynomial at the same x-coordinate.
+///
+/// The blinding share is only needed to verify the share against the commitments,
+/// and is ignored when the secret is reconstructed.
+#[derive(Clone)]
+pub struct PedersenShare<S: ScalarField> {
+    /// The share of the secret scalar.
+    share: ScalarShare<S>,
+    /// The evaluation of the blinding polynomial at the x-coordinate of the share.
+    blinding: S,
+}
+
+impl<S: ScalarField> PedersenShare<S> {
+    /// Parses a Pedersen share from its binary format.
+    ///
+    /// ## Arguments
+    /// * `bytes` - The encoded share, as written by [`PedersenShare::to_bytes`].
+    ///
+    /// ## Returns
+    /// * The decoded share if successful; otherwise, an error.
+    ///
+    /// ## Errors
+    /// * Returns an error if the format version or the group is unsupported, the length does not match
+    ///   the encoding of two scalars or the scalars are not canonically encoded.
+    pub fn from_bytes(bytes: &[u8]) -> Result<PedersenShare<S>, Error> {
+        let version = *bytes.first().ok_or(Error::ShareTooShort { index: 0, len: 0 })?;
+        if version != PEDERSEN_SHARE_FORMAT_VERSION {
+            return Err(Error::UnsupportedVersion { version });
+        }
+        let share_len = SCALAR_HEADER_LEN + S::ENCODED_LEN;
+        if bytes.len() != 1 + share_len + S::ENCODED_LEN {
+            return Err(Error::InvalidBufferLength {
+                index: 0,
+                expected: 1 + share_len + S::ENCODED_LEN,
+                found: bytes.len(),
+            });
+        }
+
+        let share = ScalarShare::from_bytes(&bytes[1..1 + share_len])?;
+        let blinding = Option::from(S::from_canonical(&bytes[1 + share_len..])).ok_or(Error::ExceedsModulus)?;
+        Ok(PedersenShare { share, blinding })
+    }
+
+    /// Encodes the share into the binary format `(version, share, blinding)`, with the share of the
+    /// secret in the binary format of [`ScalarShare::to_bytes`] and the blinding share in its canonical encoding.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(1 + SCALAR_HEADER_LEN + 2 * S::ENCODED_LEN);
+        bytes.push(PEDERSEN_SHARE_FORMAT_VERSION);
+        bytes.extend_from_slice(&self.share.to_bytes());
+        bytes.resize(1 + SCALAR_HEADER_LEN + 2 * S::ENCODED_LEN, 0);
+        self.blinding
+            .write_canonical(&mut bytes[1 + SCALAR_HEADER_LEN + S::ENCODED_LEN..]);
+        bytes
+    }
+
+    /// Returns the share `s_i` of the secret scalar, which can be combined with [`combine_scalar`](crate::combine_scalar).
+    pub fn share(&self) -> &ScalarShare<S> {
+        &self.share
+    }
+
+    /// Returns the share `t_i` of the blinding polynomial.
+    pub fn blinding(&self) -> S {
+        self.blinding
+    }
+}
+
+// The share of the secret is redacted by its own implementation.
+impl<S: ScalarField> fmt::Debug for PedersenShare<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PedersenShare")
+            .field("share", &self.share)
+            .field("blinding", &"[REDACTED]")
+            .finish()
+    }
+}
+
+// The comparison of the blinding shares is performed in constant-time, as for the shares of the secret.
+impl<S: ScalarField> PartialEq for PedersenShare<S> {
+    fn eq(&self, other: &Self) -> bool {
+        let blinding = bool::from(self.blinding.ct_eq(&other.blinding));
+        self.share == other.share && blinding
+    }
+}
+
+impl<S: ScalarField> Eq for PedersenShare<S> {}
+
+// Clear memory associated with the blinding share when the share is dropped.
+impl<S: ScalarField> Drop for PedersenShare<S> {
+    fn drop(&mut self) {
+        self.blinding.zeroize();
+    }
+}
+
+/// The shares of a Pedersen split, with the commitments published to all holders.
+type PedersenSplit<G> = (Vec<PedersenShare<<G as PrimeGroup>::Scalar>>, PedersenCommitments<G>);
+
+/// Splits a scalar into multiple shares with Pedersen's verifiable secret sharing over the group `G`.
+///
+/// Unlike [`split_feldman`](crate::split_feldman), the commitments do not reveal `g^secret`.
+/// Each holder verifies its share with [`verify_pedersen_share`], and the secret is reconstructed
+/// with [`combine_pedersen`].
+///
+/// ## Arguments
+/// * `secret` - The scalar to be split.
+/// * `parts` - Total number of shares to create.
+/// * `threshold` - Minimum number of shares required to reconstruct the secret.
+///
+/// ## Returns
+/// * A vector of shares and the commitments if successful; otherwise, an error.
+///
+/// ## Errors
+/// * Returns an error if parameters are invalid (e.g., `parts` < `threshold`).
+#[cfg(feature = "std")]
+pub fn split_pedersen<G: PrimeGroup>(
+    secret: &G::Scalar,
+    parts: usize,
+    threshold: usize,
+) -> Result<PedersenSplit<G>, Error> {
+    split_pedersen_with_rng(secret, parts, threshold, &mut rand::thread_rng())
+}
+
+/// Splits a scalar into multiple shares with Pedersen's verifiable secret sharing over the group `G`,
+/// drawing randomness from the provided generator.
+///
+/// ## Arguments
+/// * `secret` - The scalar to be split.
+/// * `parts` - Total number of shares to create.
+/// * `threshold` - Minimum number of shares required to reconstruct the secret.
+/// * `rng` - A cryptographically secure random number generator.
+///
+/// ## Returns
+/// * A vector of shares and the commitments if successful; otherwise, an error.
+///
+/// ## Errors
+/// * Returns an error if parameters are invalid (e.g., `parts` < `threshold`).
+pub fn split_pedersen_with_rng<G: PrimeGroup, R: RngCore + CryptoRng>(
+    secret: &G::Scalar,
+    parts: usize,
+    threshold: usize,
+    rng: &mut R,
+) -> Result<PedersenSplit<G>, Error> {
+    let (shares, polynomial) = deal(secret, parts, threshold, rng)?;
+    let blinding = Polynomial::generate_blinding_with_rng(threshold - 1, rng);
+
+    let (g, h) = (G::generator(), G::blinding_generator());
+    let points = polynomial
+        .coefficients()
+        .iter()
+        .zip(blinding.coefficients())
+        .map(|(a, b)| g.mul(a).add(h.mul(b)))
+        .collect();
+    let id = shares[0].id();
+
+    let shares = shares
+        .into_iter()
+        .map(|share| {
+            let blinding = blinding.evaluate(scalar_from_u16(share.x()));
+            PedersenShare { share, blinding }
+        })
+        .collect();
+    Ok((shares, PedersenCommitments { points, id }))
+}
+
+/// Verifies a share against the commitments of the dealer, by checking that `g^{s} h^{t}` is equal to
+/// the product of the commitments `C_i^{x^i}`, i.e., the commitment to the polynomials evaluated at `x`.
+///
+/// ## Arguments
+/// * `share` - The share received from the dealer.
+/// * `commitments` - The commitments published by the dealer.
+///
+/// ## Returns
+/// * `Ok(())` if the share is consistent with the commitments; otherwise, an error.
+///
+/// ## Errors
+/// * Returns an error if the share belongs to a different split or threshold, or does not match the commitments.
+pub fn verify_pedersen_share<G: PrimeGroup>(
+    share: &PedersenShare<G::Scalar>,
+    commitments: &PedersenCommitments<G>,
+) -> Result<(), Error> {
+    validate_share(&share.share, commitments.id, commitments.threshold())?;
+
+    let commitment = G::generator()
+        .mul(&share.share.y())
+        .add(G::blinding_generator().mul(&share.blinding));
+    if commitment == evaluate_points(&commitments.points, share.share.x()) {
+        Ok(())
+    } else {
+        Err(Error::ShareVerificationFailed { index: 0 })
+    }
+}
+
+/// Combines Pedersen shares to reconstruct the secret scalar, ignoring the blinding shares.
+///
+/// ## Arguments
+/// * `shares` - Shares of the secret.
+///
+/// ## Returns
+/// * The original scalar if successful; otherwise, an error.
+///
+/// ## Errors
+/// * Returns an error if shares are inconsistent or insufficient.
+pub fn combine_pedersen<S: ScalarField>(shares: &[PedersenShare<S>]) -> Result<S, Error> {
+    let shares: Vec<ScalarShare<S>> = shares.iter().map(|share| share.share.clone()).collect();
+    crate::combine_scalar(&shares)
+}
+
+// Test cases for Pedersen's verifiable secret sharing.
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use curve25519_dalek::{RistrettoPoint, Scalar};
+
+    // Every share of 'split_pedersen' is verified, and the commitments hide the secret.
+    #[test]
+    fn it_verifies_pedersen_shares() {
+        let secret = Scalar::from_bytes_mod_order([0x42; 32]);
+        let (shares, commitments) = split_pedersen::<RistrettoPoint>(&secret, 5, 3).expect("split failed");
+
+        assert_eq!(commitments.threshold(), 3);
+        assert_ne!(commitments.points()[0], RistrettoPoint::generator().mul(&secret));
+        assert_ne!(RistrettoPoint::blinding_generator(), RistrettoPoint::generator());
+        for share in shares.iter() {
+            assert_eq!(verify_pedersen_share(share, &commitments), Ok(()));
+        }
+        assert_eq!(combine_pedersen(&shares[..3]).expect("combine failed"), secret);
+        assert_eq!(combine_pedersen(&shares[1..4]).expect("combine failed"), secret);
+    }
+
+    // Shares with a corrupted share of the secret or of the blinding are rejected.
+    #[test]
+    fn it_rejects_invalid_pedersen_shares() {
+        let secret = Scalar::from(7u8);
+        let (shares, commitments) = split_pedersen::<RistrettoPoint>(&secret, 4, 2).expect("split failed");
+
+        for offset in [10, 10 + 32] {
+            let mut bytes = shares[0].to_bytes();
+            bytes[offset] ^= 0x01;
+            let corrupted = PedersenShare::from_bytes(&bytes).expect("decoding failed");
+            assert_eq!(
+                verify_pedersen_share(&corrupted, &commitments),
+                Err(Error::ShareVerificationFailed { index: 0 })
+            );
+        }
+
+        let (other, _) = split_pedersen::<RistrettoPoint>(&secret, 4, 2).expect("split failed");
+        assert_eq!(
+            verify_pedersen_share(&other[0], &commitments),
+            Err(Error::SplitIdMismatch { index: 0 })
+        );
+    }
+
+    // Encoding and decoding of the binary formats of the shares and the commitments.
+    #[test]
+    fn it_encodes_pedersen_splits() {
+        let (shares, commitments) = split_pedersen::<RistrettoPoint>(&Scalar::ONE, 3, 2).expect("split failed");
+
+        let bytes = shares[0].to_bytes();
+        assert_eq!(bytes.len(), 1 + 9 + 2 * 32);
+        assert_eq!(bytes[..2], [PEDERSEN_SHARE_FORMAT_VERSION, 0x83]);
+        assert_eq!(PedersenShare::from_bytes(&bytes), Ok(shares[0].clone()));
+        assert_eq!(
+            PedersenShare::<Scalar>::from_bytes(&bytes[..bytes.len() - 1]),
+            Err(Error::InvalidBufferLength {
+                index: 0,
+                expected: 74,
+                found: 73
+            })
+        );
+        assert_eq!(
+            PedersenShare::<Scalar>::from_bytes(&bytes[1..]),
+            Err(Error::UnsupportedVersion { version: 0x83 })
+        );
+
+        let bytes = commitments.to_bytes();
+        assert_eq!(bytes[0], PEDERSEN_FORMAT_VERSION);
+        assert_eq!(PedersenCommitments::from_bytes(&bytes), Ok(commitments));
+        assert_eq!(
+            crate::FeldmanCommitments::<RistrettoPoint>::from_bytes(&bytes),
+            Err(Error::UnsupportedVersion {
+                version: PEDERSEN_FORMAT_VERSION
+            })
+        );
+    }
+
+    // Pedersen's scheme over secp256k1.
+    #[cfg(feature = "secp256k1")]
+    #[test]
+    fn it_verifies_secp256k1_pedersen_shares() {
+        let secret = k256::Scalar::from(0xDEADBEEFu64);
+        let (shares, commitments) = split_pedersen::<k256::ProjectivePoint>(&secret, 4, 3).expect("split failed");
+
+        for share in shares.iter() {
+            assert_eq!(verify_pedersen_share(share, &commitments), Ok(()));
+        }
+        assert_eq!(combine_pedersen(&shares[1..]).expect("combine failed"), secret);
+    }
+}
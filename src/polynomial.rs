@@ -1,19 +1,26 @@
-use crate::ops;
-use rand::Rng;
+use crate::{
+    field::{Field, Gf256},
+    ops,
+};
+use alloc::{vec, vec::Vec};
+use rand::{CryptoRng, RngCore};
 use zeroize::Zeroize;
 
-/// A struct representing a polynomial with coefficients in `GF(2^8)`.
+/// A polynomial with coefficients in a finite field `F`.
 ///
 /// The index of each element in the `coefficients` vector represents the power of the corresponding term.
 /// For instance, a polynomial `ax^2 + bx + c` is represented as `coefficients: vec![c, b, a]`.
 ///
-pub struct Polynomial {
+/// The shares of this crate are evaluations of polynomials over [`Gf256`] or [`Gf65536`](crate::Gf65536),
+/// while any other implementation of [`Field`] can be plugged in to reuse the generation, evaluation and
+/// interpolation. The coefficients are zeroized when the polynomial is dropped.
+pub struct Polynomial<F: Field> {
     /// The coefficients of the polynomial, ordered from the `intercept`
     /// up to the highest-degree term.
-    coefficients: Vec<u8>,
+    coefficients: Vec<F>,
 }
 
-impl Polynomial {
+impl<F: Field> Polynomial<F> {
     /// Creates a new polynomial with a specified `intercept` and randomly generated coefficients.
     ///
     /// ## Arguments
@@ -22,19 +29,77 @@ impl Polynomial {
     ///
     /// ## Returns
     /// * The newly created Polynomial.
-    pub(crate) fn generate(intercept: u8, degree: u8) -> Polynomial {
-        // Initialize the coefficients vector with zeros
-        // in the size of the `degree`, plus 1 additional byte for the `intercept`.
-        let mut coefficients = vec![0u8; (degree + 1) as usize];
+    #[cfg(feature = "std")]
+    pub fn generate(intercept: F, degree: usize) -> Polynomial<F> {
+        Polynomial::generate_with_rng(intercept, degree, &mut rand::thread_rng())
+    }
+
+    /// Creates a new polynomial with a specified `intercept` and coefficients generated by `rng`.
+    ///
+    /// ## Arguments
+    /// * `intercept` - The constant term of the polynomial.
+    /// * `degree` - The highest power of `x` that appears in the polynomial.
+    /// * `rng` - A cryptographically secure random number generator.
+    ///
+    /// ## Returns
+    /// * The newly created Polynomial.
+    pub fn generate_with_rng<R: RngCore + CryptoRng>(intercept: F, degree: usize, rng: &mut R) -> Polynomial<F> {
+        // Initialize the coefficients vector with the `intercept`,
+        // followed by `degree` randomly generated coefficients.
+        let mut coefficients = Vec::with_capacity(degree + 1);
+        coefficients.push(intercept);
+        coefficients.extend((0..degree).map(|_| F::random(rng)));
+
+        Polynomial { coefficients }
+    }
+
+    /// Creates a new polynomial with a random `intercept` and random coefficients, such as the
+    /// blinding polynomial dealt alongside the polynomial of the secret in Pedersen's scheme.
+    ///
+    /// ## Arguments
+    /// * `degree` - The highest power of `x` that appears in the polynomial.
+    ///
+    /// ## Returns
+    /// * The newly created Polynomial.
+    #[cfg(feature = "std")]
+    pub fn generate_blinding(degree: usize) -> Polynomial<F> {
+        Polynomial::generate_blinding_with_rng(degree, &mut rand::thread_rng())
+    }
 
-        // Assign the constant-term (`intercept`) to the provided input.
-        coefficients[0] = intercept;
-        // Randomly generate the remaining coefficients.
-        rand::thread_rng().fill(&mut coefficients[1..]);
+    /// Creates a new polynomial with a random `intercept` and random coefficients generated by `rng`.
+    ///
+    /// ## Arguments
+    /// * `degree` - The highest power of `x` that appears in the polynomial.
+    /// * `rng` - A cryptographically secure random number generator.
+    ///
+    /// ## Returns
+    /// * The newly created Polynomial.
+    pub fn generate_blinding_with_rng<R: RngCore + CryptoRng>(degree: usize, rng: &mut R) -> Polynomial<F> {
+        let intercept = F::random(rng);
+        Polynomial::generate_with_rng(intercept, degree, rng)
+    }
 
+    /// Creates a polynomial from its coefficients, ordered from the `intercept`
+    /// up to the highest-degree term.
+    ///
+    /// ## Panics
+    /// * If `coefficients` is empty.
+    pub fn from_coefficients(coefficients: Vec<F>) -> Polynomial<F> {
+        assert!(!coefficients.is_empty(), "empty coefficients");
         Polynomial { coefficients }
     }
 
+    /// Returns the coefficients of the polynomial, ordered from the `intercept`
+    /// up to the highest-degree term.
+    pub fn coefficients(&self) -> &[F] {
+        &self.coefficients
+    }
+
+    /// Returns the constant term of the polynomial, equal to its value at `x = 0`.
+    pub fn intercept(&self) -> F {
+        self.coefficients[0]
+    }
+
     /// Evaluates the polynomial at a given point `x` using Horner's method.
     ///
     /// ## Arguments
@@ -46,30 +111,18 @@ impl Polynomial {
     /// ## Panics
     /// * If `x` is zero, since the evaluation at `x = 0` is not allowed.
     ///
-    /// This is a safeguard to prevent revealing the secret byte set as the constant term.
-    pub(crate) fn evaluate(&self, x: u8) -> u8 {
-        // Mathematically, evaluating a polynomial at `x = 0` is valid and results to the constant term (`self.coefficients[0]`).
-        // However, that's not allowed in order to prevent revealing the secret byte, which in this case is the constant term.
-        //
-        // The `evaluate` function is crate-level, not public; this safe=guard is implemented to prevent incorrect third-party implementations
-        // or changes in the code that could lead to accidental exposure of the secret bytes.
-        //
-        // Normally invoked from `split` with x-coordinates in the range of `1..=255`, therefor this should never occur.
-        if x == 0 {
+    /// This is a safeguard to prevent revealing the secret set as the constant term,
+    /// which is available through [`Polynomial::intercept`] instead.
+    pub fn evaluate(&self, x: F) -> F {
+        // Evaluating at `x = 0` would reveal the intercept, see `evaluate_coefficients`.
+        if bool::from(x.is_zero()) {
             panic!("evaluation not allowed for x = 0");
         }
 
-        // Start from the highest degree coefficient.
-        // Coefficients are guaranteed to have at least one element,
-        // thus the `expect` method will never cause a runtime error in a correct implementation.
-        let mut result = self.coefficients.last().copied().expect("empty coefficients");
-
-        // Iterate over the coefficients in reverse
-        for coefficient in self.coefficients.iter().rev().skip(1) {
-            // Horner's method for polynomial evaluation.
-            result = ops::add(ops::mult(result, x), *coefficient);
-        }
-        result
+        // Start from the highest degree coefficient and iterate over the coefficients in reverse.
+        let mut coefficients = self.coefficients.iter().rev();
+        let highest = *coefficients.next().expect("empty coefficients");
+        coefficients.fold(highest, |result, &coefficient| result.mul(x).add(coefficient))
     }
 
     /// Computes the value of a polynomial at a given point `x` using Lagrange interpolation.
@@ -82,87 +135,366 @@ impl Polynomial {
     /// ## Returns
     /// * The interpolated value of the polynomial at `x`.
     ///
+    /// ## Panics
+    /// * If `x_samples` contains duplicate x-values, or differences of x-values that are not invertible,
+    ///   such as in the residues of a composite modulus.
+    ///
     /// ## Notes
-    /// * This function assumes that `x_samples` and `y_samples` have the same length and contain no duplicate x-values.
-    ///
-    /// The caller must ensure this for performance reasons in order to avoid reduntant checks when iterating.
-    pub(crate) fn interpolate(x_samples: &[u8], y_samples: &[u8], x: u8) -> u8 {
-        let limit = x_samples.len();
-        let mut result = 0;
-        // Iterate over each sample to construct the Lagrange basis polynomial.
-        for i in 0..limit {
-            let mut basis = 1;
-            // Construct the basis polynomial for each i-th term.
-            for j in 0..limit {
-                if i == j {
-                    continue;
-                }
-                // Calculate the numerator and denominator for the Lagrange basis.
-                let num = ops::add(x, x_samples[j]);
-                let denom = ops::add(x_samples[i], x_samples[j]);
-                let term = ops::div(num, denom);
-                // Multiply the basis by the current term.
-                basis = ops::mult(basis, term);
-            }
-            // Multiply the y-sample by the basis and add to the result.
-            let group = ops::mult(y_samples[i], basis);
-            result = ops::add(result, group);
-        }
+    /// * This function assumes that `x_samples` and `y_samples` have the same length.
+    ///
+    /// When interpolating many polynomials through the same x-coordinates, the weights should instead be computed
+    /// once with [`Polynomial::lagrange_weights`] and applied with [`Polynomial::weighted_sum`].
+    pub fn interpolate(x_samples: &[F], y_samples: &[F], x: F) -> F {
+        let mut weights = vec![F::zero(); x_samples.len()];
+        Polynomial::lagrange_weights(x_samples, x, &mut weights);
+        let result = Polynomial::weighted_sum(&weights, y_samples);
+        weights.zeroize();
         result
     }
+
+    /// Computes the Lagrange basis polynomials of the x-coordinates evaluated at a given point `x`.
+    ///
+    /// The weights depend only on the x-coordinates, therefore the value at `x` of any polynomial
+    /// through these x-coordinates is the sum of its y-coordinates multiplied by the weights.
+    /// The numerators are computed from the products of the preceding and the following factors,
+    /// and the denominators of all weights are inverted at once with Montgomery's batch inversion,
+    /// requiring a single constant-time inversion instead of one for every term, and without
+    /// allocating, since the products of the preceding terms are held in `weights`.
+    ///
+    /// ## Arguments
+    /// * `x_samples` - Array of x-coordinates of the dataset.
+    /// * `x` - The x-coordinate at which the basis polynomials are evaluated.
+    /// * `weights` - The output buffer, in the same length as `x_samples`.
+    ///
+    /// ## Panics
+    /// * If `x_samples` contains duplicate x-values, or differences of x-values that are not invertible,
+    ///   such as in the residues of a composite modulus.
+    pub fn lagrange_weights(x_samples: &[F], x: F, weights: &mut [F]) {
+        assert!(
+            Polynomial::try_lagrange_weights(x_samples, x, weights),
+            "non-invertible x-coordinates"
+        );
+    }
+
+    /// Computes the Lagrange basis polynomials of the x-coordinates evaluated at a given point `x`,
+    /// as [`Polynomial::lagrange_weights`] does, reporting non-invertible denominators instead of panicking.
+    ///
+    /// The denominators are not invertible if `x_samples` contains duplicate x-values, or if the
+    /// differences of distinct x-values are zero divisors, as in the residues of a composite modulus.
+    ///
+    /// ## Arguments
+    /// * `x_samples` - Array of x-coordinates of the dataset.
+    /// * `x` - The x-coordinate at which the basis polynomials are evaluated.
+    /// * `weights` - The output buffer, in the same length as `x_samples`.
+    ///
+    /// ## Returns
+    /// * `true` if the weights were computed, or `false` if the denominators are not invertible,
+    ///   in which case the content of `weights` is unspecified.
+    pub(crate) fn try_lagrange_weights(x_samples: &[F], x: F, weights: &mut [F]) -> bool {
+        // The i-th weight is the product of `(x - x_j) / (x_i - x_j)` for every `j != i`.
+        let denominator = |i: usize, x_i: F| {
+            x_samples
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .fold(F::one(), |product, (_, &x_j)| product.mul(x_i.sub(x_j)))
+        };
+
+        // Accumulate the products of the preceding numerator factors and denominators, and invert the product
+        // of all denominators, which is invertible for distinct x-coordinates in a field.
+        let mut numerators = F::one();
+        let mut denominators = F::one();
+        for (i, (&x_i, weight)) in x_samples.iter().zip(weights.iter_mut()).enumerate() {
+            *weight = numerators.mul(denominators);
+            numerators = numerators.mul(x.sub(x_i));
+            denominators = denominators.mul(denominator(i, x_i));
+        }
+        // The x-coordinates are public, therefore the check may branch on whether the inversion failed.
+        let inverse = denominators.inv();
+        if bool::from(inverse.is_none()) {
+            return false;
+        }
+        let mut inverse = inverse.unwrap();
+
+        // Walking backwards, the inverse of the product up to the i-th denominator multiplied by the product of the
+        // preceding ones results in the inverse of the i-th denominator alone, and the product of the following
+        // numerator factors completes the numerator of the i-th weight.
+        let mut numerators = F::one();
+        for (i, (&x_i, weight)) in x_samples.iter().zip(weights.iter_mut()).enumerate().rev() {
+            *weight = weight.mul(inverse).mul(numerators);
+            inverse = inverse.mul(denominator(i, x_i));
+            numerators = numerators.mul(x.sub(x_i));
+        }
+
+        true
+    }
+
+    /// Computes the sum of the y-coordinates multiplied by their Lagrange weights,
+    /// resulting in the value of the polynomial at the point the weights were computed for.
+    ///
+    /// ## Arguments
+    /// * `weights` - The weights computed by [`Polynomial::lagrange_weights`].
+    /// * `y_samples` - Array of y-coordinates of the dataset, each corresponding to `weights`.
+    ///
+    /// ## Returns
+    /// * The interpolated value of the polynomial.
+    pub fn weighted_sum(weights: &[F], y_samples: &[F]) -> F {
+        weights
+            .iter()
+            .zip(y_samples.iter())
+            .fold(F::zero(), |result, (&weight, &y)| result.add(weight.mul(y)))
+    }
 }
 
 // This is important for security purposes to prevent sensitive data
 // from staying in memory after the Polynomial is no longer required and dropped.
-impl Drop for Polynomial {
+impl<F: Field> Drop for Polynomial<F> {
     fn drop(&mut self) {
         // Clear memory associated with the coefficients.
         self.coefficients.zeroize();
     }
 }
 
+/// Evaluates the polynomial over `GF(2^8)` given by its `coefficients` at a given point `x` using Horner's method,
+/// without requiring the coefficients to be owned by a `Polynomial`.
+///
+/// ## Arguments
+/// * `coefficients` - The coefficients, ordered from the `intercept` up to the highest-degree term.
+/// * `x` - The point at which to evaluate the polynomial.
+///
+/// ## Returns
+/// * The value of the polynomial at `x`.
+///
+/// ## Panics
+/// * If `x` is zero or `coefficients` is empty.
+pub(crate) fn evaluate_coefficients(coefficients: &[u8], x: u8) -> u8 {
+    // Mathematically, evaluating a polynomial at `x = 0` is valid and results to the constant term (`self.coefficients[0]`).
+    // However, that's not allowed in order to prevent revealing the secret byte, which in this case is the constant term.
+    //
+    // The function is crate-level, not public; this safe=guard is implemented to prevent incorrect third-party implementations
+    // or changes in the code that could lead to accidental exposure of the secret bytes.
+    //
+    // Normally invoked from `split` with x-coordinates in the range of `1..=255`, therefor this should never occur.
+    if x == 0 {
+        panic!("evaluation not allowed for x = 0");
+    }
+
+    // Start from the highest degree coefficient.
+    // Coefficients are guaranteed to have at least one element,
+    // thus the `expect` method will never cause a runtime error in a correct implementation.
+    let mut result = coefficients.last().copied().expect("empty coefficients");
+
+    // Iterate over the coefficients in reverse
+    for coefficient in coefficients.iter().rev().skip(1) {
+        // Horner's method for polynomial evaluation.
+        result = ops::add(ops::mult(result, x), *coefficient);
+    }
+    result
+}
+
+/// Evaluates many polynomials over `GF(2^8)` of the same degree at a given point `x` using Horner's method
+/// over whole slices.
+///
+/// The coefficients are laid out column-wise: the `columns` consist of `degree + 1` consecutive
+/// columns of `result.len()` bytes, ordered from the intercepts up to the highest-degree terms,
+/// where the i-th element of every column belongs to the i-th polynomial.
+///
+/// ## Arguments
+/// * `columns` - The coefficients of the polynomials, column by column.
+/// * `x` - The point at which to evaluate the polynomials.
+/// * `result` - The output buffer receiving the value of every polynomial at `x`.
+///
+/// ## Panics
+/// * If `x` is zero, since the evaluation at `x = 0` is not allowed.
+pub(crate) fn evaluate_columns(columns: &[u8], x: u8, result: &mut [u8]) {
+    // Evaluating at `x = 0` would reveal the intercepts, see `evaluate_coefficients`.
+    if x == 0 {
+        panic!("evaluation not allowed for x = 0");
+    }
+
+    // Start from the highest degree coefficients and iterate over the remaining columns in reverse.
+    let mut columns = columns.chunks_exact(result.len()).rev();
+    result.copy_from_slice(columns.next().expect("empty coefficients"));
+    for column in columns {
+        ops::mult_add_slice(result, x, column);
+    }
+}
+
+/// Computes the Lagrange weights of at most 256 x-coordinates in `GF(2^8)` at a given point `x`,
+/// as [`Polynomial::lagrange_weights`] does, without allocating.
+///
+/// ## Arguments
+/// * `x_samples` - Array of x-coordinates of the dataset, at most 256.
+/// * `x` - The x-coordinate at which the basis polynomials are evaluated.
+/// * `weights` - The output buffer, in the same length as `x_samples`.
+///
+/// ## Notes
+/// * This function assumes that `x_samples` contains no duplicate x-values.
+pub(crate) fn lagrange_weights(x_samples: &[u8], x: u8, weights: &mut [u8]) {
+    let mut field_samples = [Gf256(0); 256];
+    let mut field_weights = [Gf256(0); 256];
+    let limit = x_samples.len();
+    for (sample, &x_i) in field_samples.iter_mut().zip(x_samples.iter()) {
+        *sample = Gf256(x_i);
+    }
+
+    Polynomial::lagrange_weights(&field_samples[..limit], Gf256(x), &mut field_weights[..limit]);
+    for (weight, field_weight) in weights.iter_mut().zip(field_weights.iter()) {
+        *weight = field_weight.0;
+    }
+}
+
+/// Computes the sum of the y-coordinates in `GF(2^8)` multiplied by their Lagrange weights,
+/// as [`Polynomial::weighted_sum`] does over bytes.
+///
+/// ## Arguments
+/// * `weights` - The weights computed by [`lagrange_weights`].
+/// * `y_samples` - Array of y-coordinates of the dataset, each corresponding to `weights`.
+///
+/// ## Returns
+/// * The interpolated value of the polynomial.
+pub(crate) fn weighted_sum(weights: &[u8], y_samples: &[u8]) -> u8 {
+    weights
+        .iter()
+        .zip(y_samples.iter())
+        .fold(0, |result, (&weight, &y)| ops::add(result, ops::mult(weight, y)))
+}
+
 // Test cases for the operations of the Polynomial struct.
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use super::*;
+    use crate::Gf65536;
     use std::error::Error;
     // Polynomial generation with random coefficients.
     #[test]
     fn it_generates() -> Result<(), Box<dyn Error>> {
         let degree = 3;
-        let intercept = 5;
+        let intercept = Gf256(5);
         let polynomial = Polynomial::generate(intercept, degree);
 
         // The first coefficient is the intercept
         assert_eq!(polynomial.coefficients[0], intercept);
         // The number of coefficients is equal to the degree plus the intercept
-        assert_eq!(polynomial.coefficients.len(), (degree + 1) as usize);
+        assert_eq!(polynomial.coefficients.len(), degree + 1);
         Ok(())
     }
 
+    // Polynomial generation is reproducible with a seeded generator.
+    #[test]
+    fn it_generates_with_rng() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let first = Polynomial::generate_with_rng(Gf256(5), 3, &mut StdRng::seed_from_u64(7));
+        let second = Polynomial::generate_with_rng(Gf256(5), 3, &mut StdRng::seed_from_u64(7));
+
+        assert_eq!(first.coefficients[0], Gf256(5));
+        assert_eq!(first.coefficients, second.coefficients);
+    }
+
     // Polynomial interpolation with known samples.
     #[test]
     fn it_interpolates() {
         // Assign points for interpolation.
-        let x_samples = [0x3D, 0xA7, 0x1E];
-        let y_samples = [0x1A, 0x2B, 0x4C];
+        let x_samples = [0x3D, 0xA7, 0x1E].map(Gf256);
+        let y_samples = [0x1A, 0x2B, 0x4C].map(Gf256);
         // Set a specific point.
-        let x = 0x5A;
+        let x = Gf256(0x5A);
 
-        assert_eq!(Polynomial::interpolate(&x_samples, &y_samples, x), 0xCE);
+        assert_eq!(Polynomial::interpolate(&x_samples, &y_samples, x), Gf256(0xCE));
+    }
+
+    // The batch-inverted Lagrange weights match the basis polynomials computed term by term.
+    #[test]
+    fn it_computes_lagrange_weights() {
+        let x_samples = [0x3D, 0xA7, 0x1E, 0x01, 0xFF];
+
+        for x in [0x00, 0x5A, 0xA7] {
+            let mut weights = [0u8; 5];
+            lagrange_weights(&x_samples, x, &mut weights);
+
+            for (i, &weight) in weights.iter().enumerate() {
+                let basis = (0..x_samples.len()).filter(|&j| j != i).fold(1, |basis, j| {
+                    let term = ops::div(ops::add(x, x_samples[j]), ops::add(x_samples[i], x_samples[j]));
+                    ops::mult(basis, term)
+                });
+                assert_eq!(weight, basis);
+            }
+
+            // The weights interpolate the constant polynomial `1`, therefore they sum up to one.
+            assert_eq!(weights.iter().fold(0, |sum, &weight| ops::add(sum, weight)), 1);
+        }
+
+        // A polynomial of degree 4 is recovered at the intercept from the weights at `x = 0`.
+        let polynomial = Polynomial::from_coefficients([0x7C, 0x3E, 0x4F, 0x2A, 0x07].map(Gf256).to_vec());
+        let y_samples: Vec<u8> = x_samples.iter().map(|&x| polynomial.evaluate(Gf256(x)).0).collect();
+        let mut weights = [0u8; 5];
+        lagrange_weights(&x_samples, 0, &mut weights);
+        assert_eq!(weighted_sum(&weights, &y_samples), polynomial.intercept().0);
+    }
+
+    // Generation, evaluation and interpolation are generic over the field.
+    #[test]
+    fn it_interpolates_over_any_field() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let polynomial = Polynomial::generate_with_rng(Gf65536(0xBEEF), 299, &mut StdRng::seed_from_u64(7));
+        let x_samples: Vec<Gf65536> = (1..=300).map(|x| Gf65536(x * 211)).collect();
+        let y_samples: Vec<Gf65536> = x_samples.iter().map(|&x| polynomial.evaluate(x)).collect();
+
+        assert_eq!(
+            Polynomial::interpolate(&x_samples, &y_samples, Gf65536::zero()),
+            Gf65536(0xBEEF)
+        );
+        assert_eq!(
+            Polynomial::interpolate(&x_samples, &y_samples, Gf65536(0x0001)),
+            polynomial.evaluate(Gf65536(0x0001))
+        );
+        // Fewer samples than coefficients interpolate a different polynomial.
+        assert_ne!(
+            Polynomial::interpolate(&x_samples[1..], &y_samples[1..], Gf65536::zero()),
+            Gf65536(0xBEEF)
+        );
+    }
+
+    // Column-wise evaluation matches the evaluation of every polynomial on its own.
+    #[test]
+    fn it_evaluates_columns() {
+        let polynomials = [
+            Polynomial::from_coefficients([0x7C, 0x3E, 0x4F, 0x2A].map(Gf256).to_vec()),
+            Polynomial::from_coefficients([0x00, 0xFF, 0x01, 0x80].map(Gf256).to_vec()),
+            Polynomial::from_coefficients([0x12, 0x00, 0x00, 0x00].map(Gf256).to_vec()),
+        ];
+        // Lay out the coefficients column by column.
+        let columns: Vec<u8> = (0..4)
+            .flat_map(|degree| {
+                polynomials
+                    .iter()
+                    .map(move |polynomial| polynomial.coefficients[degree].0)
+            })
+            .collect();
+
+        for x in [0x01, 0x2A, 0xFF] {
+            let mut result = [0u8; 3];
+            evaluate_columns(&columns, x, &mut result);
+            for (value, polynomial) in result.iter().zip(polynomials.iter()) {
+                assert_eq!(*value, polynomial.evaluate(Gf256(x)).0);
+            }
+        }
     }
 
     // Polynomial evaluation with known coefficients.
     #[test]
     fn it_evaluates() {
         // Assign coefficients for the polynomial.
-        let coefficients = vec![0x7C, 0x3E, 0x4F, 0x2A, 0x07];
+        let coefficients = [0x7C, 0x3E, 0x4F, 0x2A, 0x07];
         // Create a polynomial with the coefficients.
-        let polynomial = Polynomial { coefficients };
+        let polynomial = Polynomial::from_coefficients(coefficients.map(Gf256).to_vec());
         // Set a specific point.
         let x = 0x2A;
 
-        assert_eq!(polynomial.evaluate(x), 0xEF);
+        assert_eq!(polynomial.evaluate(Gf256(x)), Gf256(0xEF));
+        assert_eq!(evaluate_coefficients(&coefficients, x), 0xEF);
     }
 
     // Polynomial evaluation with known coefficients.
@@ -170,11 +502,11 @@ mod tests {
     #[should_panic(expected = "evaluation not allowed for x = 0")]
     fn it_fails_to_evaluate_zero() {
         // Assign coefficients for the polynomial.
-        let coefficients = vec![0x7C, 0x3E, 0x4F, 0x2A, 0x07];
+        let coefficients = [0x7C, 0x3E, 0x4F, 0x2A, 0x07].map(Gf256).to_vec();
         // Create a polynomial with the coefficients.
         let polynomial = Polynomial { coefficients };
         // Set a specific point.
-        let x = 0x00;
+        let x = Gf256(0x00);
 
         polynomial.evaluate(x);
     }
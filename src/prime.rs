@@ -0,0 +1,564 @@
+use crate::{field::Field, polynomial::Polynomial, unique_wide_x_coordinates, validate_parameters, Error};
+use alloc::{vec, vec::Vec};
+use core::fmt;
+use crypto_bigint::{
+    modular::constant_mod::{Residue, ResidueParams},
+    Limb, Random, Uint,
+};
+use rand::{CryptoRng, Rng, RngCore};
+use subtle::{ConstantTimeEq, ConstantTimeLess, CtOption};
+use zeroize::Zeroize;
+
+/// The version of the binary format of prime shares written by [`PrimeShare::to_bytes`].
+///
+/// The high bit distinguishes prime shares from the shares of [`FORMAT_VERSION`](crate::FORMAT_VERSION).
+pub const PRIME_FORMAT_VERSION: u8 = 0x82;
+
+/// The maximum number of prime shares, bounded by the 2-byte x-coordinates of the binary format.
+pub const MAX_PRIME_PARTS: usize = u16::MAX as usize;
+
+/// The length of the header in the binary format of prime shares: version (1 byte),
+/// threshold (2 bytes), split identifier (4 bytes) and x-coordinate (2 bytes).
+const PRIME_HEADER_LEN: usize = 9;
+
+// The residues modulo a prime `MOD` form a field, with constant-time arithmetic in Montgomery form.
+// The modulus is not checked to be prime; for composite moduli, `combine_prime` returns
+// `Error::NonInvertibleCoordinates` if the differences of the x-coordinates are not invertible.
+impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Field for Residue<MOD, LIMBS> {
+    fn zero() -> Self {
+        Residue::ZERO
+    }
+
+    fn one() -> Self {
+        Residue::ONE
+    }
+
+    fn add(self, other: Self) -> Self {
+        Residue::add(&self, &other)
+    }
+
+    fn sub(self, other: Self) -> Self {
+        Residue::sub(&self, &other)
+    }
+
+    fn mul(self, other: Self) -> Self {
+        Residue::mul(&self, &other)
+    }
+
+    fn inv(self) -> CtOption<Self> {
+        let (inverse, invertible) = Residue::invert(&self);
+        CtOption::new(inverse, invertible.into())
+    }
+
+    fn random<R: RngCore + CryptoRng>(rng: &mut R) -> Self {
+        <Residue<MOD, LIMBS> as Random>::random(rng)
+    }
+}
+
+/// A single share of an integer secret split over the prime field `GF(p)`, where the prime `p`
+/// is given by the modulus `MOD` of `LIMBS` limbs, such as a 256-bit prime in a [`crypto_bigint::U256`].
+///
+/// Prime shares carry the threshold and a random split identifier as wide shares do, and are encoded
+/// in the binary format of [`PrimeShare::to_bytes`], with a y-coordinate of a fixed length.
+#[derive(Clone)]
+pub struct PrimeShare<MOD: ResidueParams<LIMBS>, const LIMBS: usize> {
+    /// The x-coordinate at which the polynomial was evaluated.
+    x: u16,
+    /// The y-coordinate, in Montgomery form.
+    y: Residue<MOD, LIMBS>,
+    /// Minimum number of shares required to reconstruct the secret.
+    threshold: u16,
+    /// Random identifier shared by all shares of the same split.
+    id: u32,
+}
+
+impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> PrimeShare<MOD, LIMBS> {
+    /// The length of the binary format, with the y-coordinate in as many bytes as the modulus.
+    const ENCODED_LEN: usize = PRIME_HEADER_LEN + LIMBS * Limb::BYTES;
+
+    /// Parses a prime share from its binary format.
+    ///
+    /// ## Arguments
+    /// * `bytes` - The encoded share, as written by [`PrimeShare::to_bytes`].
+    ///
+    /// ## Returns
+    /// * The decoded share if successful; otherwise, an error.
+    ///
+    /// ## Errors
+    /// * Returns an error if the format version is unsupported, the length does not match the size
+    ///   of the modulus or the y-coordinate is not less than the modulus.
+    pub fn from_bytes(bytes: &[u8]) -> Result<PrimeShare<MOD, LIMBS>, Error> {
+        let version = *bytes.first().ok_or(Error::ShareTooShort { index: 0, len: 0 })?;
+        if version != PRIME_FORMAT_VERSION {
+            return Err(Error::UnsupportedVersion { version });
+        }
+        if bytes.len() != Self::ENCODED_LEN {
+            return Err(Error::InvalidBufferLength {
+                index: 0,
+                expected: Self::ENCODED_LEN,
+                found: bytes.len(),
+            });
+        }
+
+        let threshold = u16::from_be_bytes([bytes[1], bytes[2]]);
+        if threshold < 2 {
+            return Err(Error::ThresholdTooSmall {
+                threshold: threshold as usize,
+            });
+        }
+
+        // The encoding of every residue is unique, therefore larger values are rejected instead of reduced.
+        let mut y = Uint::<LIMBS>::from_be_slice(&bytes[PRIME_HEADER_LEN..]);
+        let canonical = y.ct_lt(&MOD::MODULUS);
+        let share = PrimeShare {
+            x: u16::from_be_bytes([bytes[7], bytes[8]]),
+            y: Residue::new(&y),
+            threshold,
+            id: u32::from_be_bytes([bytes[3], bytes[4], bytes[5], bytes[6]]),
+        };
+        y.zeroize();
+
+        if bool::from(canonical) {
+            Ok(share)
+        } else {
+            Err(Error::ExceedsModulus)
+        }
+    }
+
+    /// Encodes the share into the binary format `(version, threshold, id, x, y)`,
+    /// with every value in big-endian and the y-coordinate in as many bytes as the modulus.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(Self::ENCODED_LEN);
+        bytes.push(PRIME_FORMAT_VERSION);
+        bytes.extend_from_slice(&self.threshold.to_be_bytes());
+        bytes.extend_from_slice(&self.id.to_be_bytes());
+        bytes.extend_from_slice(&self.x.to_be_bytes());
+
+        let mut y = self.y.retrieve();
+        for limb in y.as_limbs().iter().rev() {
+            bytes.extend_from_slice(&limb.0.to_be_bytes());
+        }
+        y.zeroize();
+        bytes
+    }
+
+    /// Returns the x-coordinate of the share.
+    pub fn x(&self) -> u16 {
+        self.x
+    }
+
+    /// Returns the minimum number of shares required to reconstruct the secret.
+    pub fn threshold(&self) -> usize {
+        self.threshold as usize
+    }
+
+    /// Returns the random identifier shared by all shares of the same split.
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Returns the y-coordinate of the share, less than the modulus.
+    pub fn y(&self) -> Uint<LIMBS> {
+        self.y.retrieve()
+    }
+}
+
+// The y-coordinate is redacted to prevent shares from leaking into logs.
+impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> fmt::Debug for PrimeShare<MOD, LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PrimeShare")
+            .field("x", &self.x)
+            .field("y", &"[REDACTED]")
+            .field("threshold", &self.threshold)
+            .field("id", &self.id)
+            .finish()
+    }
+}
+
+// The comparison of the coordinates is performed in constant-time,
+// while the metadata is considered public information.
+impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> PartialEq for PrimeShare<MOD, LIMBS> {
+    fn eq(&self, other: &Self) -> bool {
+        let coordinates = self.x.ct_eq(&other.x) & self.y.ct_eq(&other.y);
+        bool::from(coordinates) && self.threshold == other.threshold && self.id == other.id
+    }
+}
+
+impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Eq for PrimeShare<MOD, LIMBS> {}
+
+// Clear memory associated with the coordinates when the share is dropped.
+impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Drop for PrimeShare<MOD, LIMBS> {
+    fn drop(&mut self) {
+        self.x.zeroize();
+        self.y.zeroize();
+    }
+}
+
+/// Returns the maximum number of shares over the prime field of `MOD`, bounded by the non-zero
+/// elements of the field for primes below `2^16`, and by the 2-byte x-coordinates otherwise.
+fn max_parts<MOD: ResidueParams<LIMBS>, const LIMBS: usize>() -> usize {
+    if MOD::MODULUS <= Uint::from_u32(MAX_PRIME_PARTS as u32) {
+        MOD::MODULUS.as_limbs()[0].0 as usize - 1
+    } else {
+        MAX_PRIME_PARTS
+    }
+}
+
+/// Splits an integer secret into multiple shares over the prime field of `MOD`.
+///
+/// ## Arguments
+/// * `secret` - The secret to be split, less than the modulus.
+/// * `parts` - Total number of shares to create.
+/// * `threshold` - Minimum number of shares required to reconstruct the secret.
+///
+/// ## Returns
+/// * A vector of shares if successful; otherwise, an error.
+///
+/// ## Errors
+/// * Returns an error if parameters are invalid (e.g., `parts` < `threshold`)
+///   or the secret is not less than the modulus.
+#[cfg(feature = "std")]
+pub fn split_prime<MOD: ResidueParams<LIMBS>, const LIMBS: usize>(
+    secret: &Uint<LIMBS>,
+    parts: usize,
+    threshold: usize,
+) -> Result<Vec<PrimeShare<MOD, LIMBS>>, Error> {
+    split_prime_with_rng(secret, parts, threshold, &mut rand::thread_rng())
+}
+
+/// Splits an integer secret into multiple shares over the prime field of `MOD`,
+/// drawing randomness from the provided generator.
+///
+/// ## Arguments
+/// * `secret` - The secret to be split, less than the modulus.
+/// * `parts` - Total number of shares to create.
+/// * `threshold` - Minimum number of shares required to reconstruct the secret.
+/// * `rng` - A cryptographically secure random number generator.
+///
+/// ## Returns
+/// * A vector of shares if successful; otherwise, an error.
+///
+/// ## Errors
+/// * Returns an error if parameters are invalid (e.g., `parts` < `threshold`)
+///   or the secret is not less than the modulus.
+pub fn split_prime_with_rng<MOD: ResidueParams<LIMBS>, const LIMBS: usize, R: RngCore + CryptoRng>(
+    secret: &Uint<LIMBS>,
+    parts: usize,
+    threshold: usize,
+    rng: &mut R,
+) -> Result<Vec<PrimeShare<MOD, LIMBS>>, Error> {
+    // Validate the input parameters.
+    validate_parameters(parts, threshold, max_parts::<MOD, LIMBS>())?;
+    if !bool::from(secret.ct_lt(&MOD::MODULUS)) {
+        return Err(Error::ExceedsModulus);
+    }
+
+    // Sample distinct non-zero x-coordinates, without shuffling all elements of the field.
+    let x_coordinates = rand::seq::index::sample(rng, max_parts::<MOD, LIMBS>(), parts);
+    let id = rng.gen();
+
+    // For a polynomial of degree `k−1`, you need `k` distinct points to uniquely determine it.
+    let polynomial = Polynomial::generate_with_rng(Residue::new(secret), threshold - 1, rng);
+
+    Ok(x_coordinates
+        .iter()
+        .map(|x| {
+            let x = x as u16 + 1;
+            PrimeShare {
+                x,
+                y: polynomial.evaluate(Residue::new(&Uint::from_u16(x))),
+                threshold: threshold as u16,
+                id,
+            }
+        })
+        .collect())
+}
+
+/// Combines shares over the prime field of `MOD` to reconstruct the integer secret.
+///
+/// ## Arguments
+/// * `shares` - Shares of the secret.
+///
+/// ## Returns
+/// * The original secret if successful; otherwise, an error.
+///
+/// ## Errors
+/// * Returns an error if shares are inconsistent or insufficient.
+/// * Returns [`Error::NonInvertibleCoordinates`] if the modulus is not prime and the differences
+///   of the x-coordinates are not invertible.
+pub fn combine_prime<MOD: ResidueParams<LIMBS>, const LIMBS: usize>(
+    shares: &[PrimeShare<MOD, LIMBS>],
+) -> Result<Uint<LIMBS>, Error> {
+    validate_prime_shares(shares)?;
+
+    // The secret is the constant term of the polynomial, located at `x = 0`.
+    let x_samples: Vec<_> = shares
+        .iter()
+        .map(|share| Residue::new(&Uint::from_u16(share.x)))
+        .collect();
+    let mut weights = vec![Residue::ZERO; shares.len()];
+    if !Polynomial::try_lagrange_weights(&x_samples, Residue::ZERO, &mut weights) {
+        return Err(Error::NonInvertibleCoordinates);
+    }
+    let mut y_samples: Vec<_> = shares.iter().map(|share| share.y).collect();
+    let mut secret = Polynomial::weighted_sum(&weights, &y_samples);
+    y_samples.zeroize();
+
+    let result = secret.retrieve();
+    secret.zeroize();
+    Ok(result)
+}
+
+/// Generates update keys and refreshes the shares over the prime field of `MOD`.
+///
+/// ## Arguments
+/// * `shares` - Current shares to be refreshed
+/// * `threshold` - Minimum number of shares required to reconstruct the secret.
+///
+/// ## Returns
+/// * Updated shares if successful; otherwise, an error.
+///
+/// ## Errors
+/// * Returns an error if shares are inconsistent or invalid.
+#[cfg(all(feature = "refresh", feature = "std"))]
+pub fn refresh_prime<MOD: ResidueParams<LIMBS>, const LIMBS: usize>(
+    shares: &[PrimeShare<MOD, LIMBS>],
+    threshold: usize,
+) -> Result<Vec<PrimeShare<MOD, LIMBS>>, Error> {
+    refresh_prime_with_rng(shares, threshold, &mut rand::thread_rng())
+}
+
+/// Generates update keys and refreshes the shares over the prime field of `MOD`,
+/// drawing randomness from the provided generator.
+///
+/// ## Arguments
+/// * `shares` - Current shares to be refreshed
+/// * `threshold` - Minimum number of shares required to reconstruct the secret.
+/// * `rng` - A cryptographically secure random number generator.
+///
+/// ## Returns
+/// * Updated shares if successful; otherwise, an error.
+///
+/// ## Errors
+/// * Returns an error if shares are inconsistent or invalid.
+#[cfg(feature = "refresh")]
+pub fn refresh_prime_with_rng<MOD: ResidueParams<LIMBS>, const LIMBS: usize, R: RngCore + CryptoRng>(
+    shares: &[PrimeShare<MOD, LIMBS>],
+    threshold: usize,
+    rng: &mut R,
+) -> Result<Vec<PrimeShare<MOD, LIMBS>>, Error> {
+    // Validate inputs
+    validate_parameters(shares.len(), threshold, max_parts::<MOD, LIMBS>())?;
+    validate_prime_shares(shares)?;
+    // The threshold of the refresh polynomial must match the threshold of the split.
+    if shares[0].threshold() != threshold {
+        return Err(Error::ThresholdMismatch {
+            index: 0,
+            expected: shares[0].threshold(),
+            found: threshold,
+        });
+    }
+
+    // Create a random polynomial with f(0) = 0 to maintain the original secret,
+    // and add its value at the x-coordinate of every share.
+    let polynomial = Polynomial::generate_with_rng(Residue::ZERO, threshold - 1, rng);
+    let mut new_shares = shares.to_vec();
+    for share in new_shares.iter_mut() {
+        let refresh_value = polynomial.evaluate(Residue::new(&Uint::from_u16(share.x)));
+        share.y = Residue::add(&share.y, &refresh_value);
+    }
+
+    Ok(new_shares)
+}
+
+/// Validates that the shares are consistent and sufficient, with distinct x-coordinates in the field.
+fn validate_prime_shares<MOD: ResidueParams<LIMBS>, const LIMBS: usize>(
+    shares: &[PrimeShare<MOD, LIMBS>],
+) -> Result<(), Error> {
+    let first = shares.first().ok_or(Error::TooFewShares {
+        provided: 0,
+        required: 2,
+    })?;
+
+    for (index, share) in shares.iter().enumerate().skip(1) {
+        if share.id != first.id {
+            return Err(Error::SplitIdMismatch { index });
+        }
+        if share.threshold != first.threshold {
+            return Err(Error::ThresholdMismatch {
+                index,
+                expected: first.threshold(),
+                found: share.threshold(),
+            });
+        }
+    }
+    if shares.len() < first.threshold() {
+        return Err(Error::TooFewShares {
+            provided: shares.len(),
+            required: first.threshold(),
+        });
+    }
+
+    // The x-coordinates of decoded shares may exceed the elements of small fields.
+    let max = max_parts::<MOD, LIMBS>();
+    if shares.iter().any(|share| share.x as usize > max) {
+        return Err(Error::ExceedsModulus);
+    }
+    unique_wide_x_coordinates(shares.iter().map(PrimeShare::x))?;
+
+    Ok(())
+}
+
+// Test cases for the shares over prime fields.
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crypto_bigint::{impl_modulus, Encoding, U256, U64};
+
+    // The order of the scalar field of BLS12-381, a 255-bit prime.
+    impl_modulus!(
+        Bls12Scalar,
+        U256,
+        "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001"
+    );
+    // The largest prime below `2^16`, which bounds the number of shares.
+    impl_modulus!(SmallPrime, U64, "000000000000FFF1");
+
+    type Share256 = PrimeShare<Bls12Scalar, { U256::LIMBS }>;
+
+    // The 'combine_prime' function with shares from the 'split_prime' function.
+    #[test]
+    fn it_combines_prime_shares() {
+        let secret = U256::from_be_hex("0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef");
+        let shares: Vec<Share256> = split_prime(&secret, 5, 3).expect("split failed");
+
+        assert_eq!(combine_prime(&shares[..3]).expect("combine failed"), secret);
+        assert_eq!(combine_prime(&shares[2..]).expect("combine failed"), secret);
+        assert_eq!(combine_prime(&shares).expect("combine failed"), secret);
+
+        // The largest element of the field is a valid secret.
+        let largest = Bls12Scalar::MODULUS.wrapping_sub(&U256::ONE);
+        let shares: Vec<Share256> = split_prime(&largest, 3, 2).expect("split failed");
+        assert_eq!(combine_prime(&shares[1..]).expect("combine failed"), largest);
+    }
+
+    // Encoding and decoding of the binary format of prime shares.
+    #[test]
+    fn it_encodes_prime_format() {
+        let shares: Vec<Share256> = split_prime(&U256::from_u64(42), 3, 2).expect("split failed");
+        let bytes = shares[0].to_bytes();
+        assert_eq!(bytes.len(), 9 + 32);
+        assert_eq!(bytes[..3], [PRIME_FORMAT_VERSION, 0, 2]);
+        assert_eq!(bytes[7..9], shares[0].x().to_be_bytes());
+        assert_eq!(U256::from_be_slice(&bytes[9..]), shares[0].y());
+
+        let decoded = Share256::from_bytes(&bytes).expect("decoding failed");
+        assert_eq!(decoded, shares[0]);
+        assert_eq!(decoded.id(), shares[0].id());
+
+        assert_eq!(
+            Share256::from_bytes(&[0x81, 0, 2]),
+            Err(Error::UnsupportedVersion { version: 0x81 })
+        );
+        assert_eq!(
+            Share256::from_bytes(&bytes[..40]),
+            Err(Error::InvalidBufferLength {
+                index: 0,
+                expected: 41,
+                found: 40
+            })
+        );
+
+        // A y-coordinate equal to the modulus is not a canonical encoding.
+        let mut bytes = bytes;
+        bytes[9..].copy_from_slice(
+            &U256::from_be_hex("73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001").to_be_bytes(),
+        );
+        assert_eq!(Share256::from_bytes(&bytes), Err(Error::ExceedsModulus));
+    }
+
+    // The shares are refreshed without changing the secret.
+    #[cfg(feature = "refresh")]
+    #[test]
+    fn it_refreshes_prime_shares() {
+        let secret = U256::from_u64(0xDEADBEEF);
+        let shares: Vec<Share256> = split_prime(&secret, 4, 3).expect("split failed");
+        let refreshed = refresh_prime(&shares, 3).expect("refresh failed");
+
+        assert!(refreshed.iter().zip(shares.iter()).all(|(new, old)| new.y() != old.y()));
+        assert_eq!(combine_prime(&refreshed[1..]).expect("combine failed"), secret);
+        assert_eq!(
+            refresh_prime(&shares, 2),
+            Err(Error::ThresholdMismatch {
+                index: 0,
+                expected: 3,
+                found: 2
+            })
+        );
+    }
+
+    // Invalid parameters and insufficient or mixed shares are reported.
+    #[test]
+    fn it_reports_prime_errors() {
+        type SmallShare = PrimeShare<SmallPrime, { U64::LIMBS }>;
+
+        assert_eq!(
+            split_prime::<SmallPrime, { U64::LIMBS }>(&U64::from_u64(7), 65521, 3),
+            Err(Error::PartsExceedField {
+                parts: 65521,
+                max: 65520
+            })
+        );
+        assert_eq!(
+            split_prime::<SmallPrime, { U64::LIMBS }>(&U64::from_u64(65521), 5, 3),
+            Err(Error::ExceedsModulus)
+        );
+
+        let shares: Vec<SmallShare> = split_prime(&U64::from_u64(65520), 5, 3).expect("split failed");
+        assert_eq!(combine_prime(&shares[2..]), Ok(U64::from_u64(65520)));
+        assert_eq!(
+            combine_prime(&shares[..2]),
+            Err(Error::TooFewShares {
+                provided: 2,
+                required: 3
+            })
+        );
+        let duplicate = [shares[0].clone(), shares[1].clone(), shares[0].clone()];
+        assert_eq!(combine_prime(&duplicate), Err(Error::DuplicateXCoordinate { index: 2 }));
+
+        let other: Vec<SmallShare> = split_prime(&U64::from_u64(1), 5, 3).expect("split failed");
+        let mixed = [shares[0].clone(), shares[1].clone(), other[2].clone()];
+        assert_eq!(combine_prime(&mixed), Err(Error::SplitIdMismatch { index: 2 }));
+    }
+
+    // A decoded share at `x = 0` is reported as such, rather than as a duplicate.
+    #[test]
+    fn it_fails_to_combine_zero_x_coordinate() {
+        let shares: Vec<Share256> = split_prime(&U256::from_u64(42), 5, 3).expect("split failed");
+        let mut bytes = shares[1].to_bytes();
+        bytes[7..9].fill(0);
+        let zero = Share256::from_bytes(&bytes).expect("decoding failed");
+
+        let selected = [shares[0].clone(), zero, shares[2].clone()];
+        assert_eq!(combine_prime(&selected), Err(Error::ZeroXCoordinate { index: 1 }));
+    }
+
+    // Shares over a composite modulus whose x-coordinates differ by a zero divisor are reported instead of panicking.
+    #[test]
+    fn it_reports_composite_modulus() {
+        // The composite modulus `21 = 3 * 7`, in which `4 - 1 = 3` is not invertible.
+        impl_modulus!(Composite, U64, "0000000000000015");
+
+        let share = |x: u16, y: u64| PrimeShare::<Composite, { U64::LIMBS }> {
+            x,
+            y: Residue::new(&U64::from_u64(y)),
+            threshold: 2,
+            id: 7,
+        };
+        assert_eq!(
+            combine_prime(&[share(1, 5), share(4, 11)]),
+            Err(Error::NonInvertibleCoordinates)
+        );
+        assert_eq!(combine_prime(&[share(1, 5), share(3, 11)]), Ok(U64::from_u64(2)));
+    }
+}
@@ -0,0 +1,388 @@
+use crate::{ops, polynomial::Polynomial, validate_threshold, x_samples, Error, Gf256, Share};
+use alloc::{vec, vec::Vec};
+use core::fmt;
+use zeroize::{Zeroize, Zeroizing};
+
+/// The result of a robust reconstruction, holding the secret
+/// and the indices of the shares that were found to be corrupted.
+pub struct Recovery {
+    /// The reconstructed secret.
+    secret: Vec<u8>,
+    /// The indices of the corrupted shares, in ascending order.
+    faulty: Vec<usize>,
+}
+
+impl Recovery {
+    /// Returns the reconstructed secret.
+    pub fn secret(&self) -> &[u8] {
+        &self.secret
+    }
+
+    /// Returns the indices of the corrupted shares within the input, in ascending order.
+    pub fn faulty(&self) -> &[usize] {
+        &self.faulty
+    }
+
+    /// Consumes the recovery and returns the reconstructed secret.
+    pub fn into_secret(mut self) -> Vec<u8> {
+        core::mem::take(&mut self.secret)
+    }
+}
+
+// The secret is redacted to prevent it from leaking into logs.
+impl fmt::Debug for Recovery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Recovery")
+            .field("secret", &format_args!("[REDACTED; {}]", self.secret.len()))
+            .field("faulty", &self.faulty)
+            .finish()
+    }
+}
+
+// This is important for security purposes to prevent sensitive data
+// from staying in memory after the Recovery is no longer required and dropped.
+impl Drop for Recovery {
+    fn drop(&mut self) {
+        // Clear memory associated with the secret.
+        self.secret.zeroize();
+    }
+}
+
+/// Combines shares to reconstruct the secret, correcting corrupted shares.
+///
+/// Shares are codewords of a Reed-Solomon code, therefore `n` shares of a secret with the given `threshold`
+/// allow up to `(n - threshold) / 2` corrupted shares to be corrected. Each byte position is decoded
+/// independently with the Berlekamp-Welch algorithm, and a share is reported as faulty if it disagrees
+/// with the decoded polynomial at any position.
+///
+/// Unlike [`combine_shares`](crate::combine_shares), the decoding relies on Gaussian elimination
+/// whose execution time depends on the values of the shares.
+///
+/// ## Arguments
+/// * `shares` - Shares of the secret.
+/// * `threshold` - Minimum number of shares required to reconstruct the secret.
+///
+/// ## Returns
+/// * The secret and the indices of the corrupted shares if successful; otherwise, an error.
+///
+/// ## Errors
+/// * Returns an error if shares are inconsistent or insufficient.
+/// * Returns [`Error::ZeroXCoordinate`] if a share has the x-coordinate zero.
+/// * Returns [`Error::TooManyFaultyShares`] if more shares are corrupted than can be corrected.
+pub fn combine_robust(shares: &[Share], threshold: usize) -> Result<Recovery, Error> {
+    validate_threshold(shares, threshold)?;
+    let x_samples = x_samples(shares)?;
+    // The decoded polynomials must not be evaluated at `x = 0`, which is not the x-coordinate of a valid share.
+    if let Some(index) = x_samples.iter().position(|&x| x == 0) {
+        return Err(Error::ZeroXCoordinate { index });
+    }
+
+    // The maximum number of errors that can be corrected at each byte position.
+    let max_errors = (shares.len() - threshold) / 2;
+
+    let mut recovery = Recovery {
+        secret: vec![0u8; shares[0].payload_len()],
+        faulty: Vec::new(),
+    };
+    // A column of y-coordinates suffices to reconstruct a byte of the secret, therefore it is
+    // zeroized on every exit, also when decoding fails.
+    let mut y_samples = Zeroizing::new(vec![0u8; shares.len()]);
+    let mut faulty = vec![false; shares.len()];
+
+    for (idx, byte) in recovery.secret.iter_mut().enumerate() {
+        for (sample, share) in y_samples.iter_mut().zip(shares.iter()) {
+            *sample = share.y()[idx];
+        }
+
+        // The decoded polynomial clears its coefficients when dropped, also on the early returns below.
+        let polynomial = decode(&x_samples, &y_samples, threshold, max_errors)
+            .ok_or(Error::TooManyFaultyShares { max: max_errors })?;
+
+        // Mark every share that does not lie on the decoded polynomial.
+        let mut errors = 0;
+        for (i, (&x, &y)) in x_samples.iter().zip(y_samples.iter()).enumerate() {
+            if polynomial.evaluate(Gf256(x)) != Gf256(y) {
+                faulty[i] = true;
+                errors += 1;
+            }
+        }
+        if errors > max_errors {
+            return Err(Error::TooManyFaultyShares { max: max_errors });
+        }
+
+        *byte = polynomial.intercept().0;
+    }
+
+    recovery.faulty = faulty.iter().enumerate().filter(|(_, &f)| f).map(|(i, _)| i).collect();
+    Ok(recovery)
+}
+
+/// Decodes the polynomial of degree less than `k` passing through all but at most `e` of the samples,
+/// using the Berlekamp-Welch algorithm.
+///
+/// The error locator `E(x)`, a monic polynomial of degree `e`, and `Q(x) = P(x) * E(x)` of degree
+/// less than `k + e` satisfy `Q(x_i) = y_i * E(x_i)` for every sample. The resulting linear system
+/// is solved for the coefficients of both polynomials and `P(x)` is recovered as `Q(x) / E(x)`.
+///
+/// ## Returns
+/// * The decoded polynomial, or `None` if more than `e` samples are corrupted.
+fn decode(x_samples: &[u8], y_samples: &[u8], k: usize, e: usize) -> Option<Polynomial<Gf256>> {
+    // The unknowns are the `k + e` coefficients of `Q(x)`, followed by the `e` lower coefficients of `E(x)`.
+    let unknowns = k + 2 * e;
+
+    // Each sample contributes the equation:
+    // q_0 + q_1 x_i + .. + q_{k+e-1} x_i^{k+e-1} + y_i (e_0 + e_1 x_i + .. + e_{e-1} x_i^{e-1}) = y_i x_i^e
+    // where subtraction is equivalent to addition in `GF(2^8)`.
+    let mut rows: Vec<Vec<u8>> = x_samples
+        .iter()
+        .zip(y_samples.iter())
+        .map(|(&x, &y)| {
+            let mut row = vec![0u8; unknowns + 1];
+            let mut power = 1;
+            for j in 0..(k + e) {
+                row[j] = power;
+                if j < e {
+                    row[k + e + j] = ops::mult(y, power);
+                }
+                if j == e {
+                    row[unknowns] = ops::mult(y, power);
+                }
+                power = ops::mult(power, x);
+            }
+            row
+        })
+        .collect();
+
+    let solution = solve(&mut rows, unknowns);
+    rows.zeroize();
+    let mut solution = solution?;
+
+    // `E(x)` is monic, its leading coefficient is not part of the solution.
+    let mut locator = solution.split_off(k + e);
+    locator.push(1);
+
+    let quotient = divide(&mut solution, &locator);
+    solution.zeroize();
+    locator.zeroize();
+    quotient.map(|mut quotient| {
+        let polynomial = Polynomial::from_coefficients(quotient.iter().map(|&c| Gf256(c)).collect());
+        quotient.zeroize();
+        polynomial
+    })
+}
+
+/// Solves the augmented linear system `rows` in `GF(2^8)` with Gaussian elimination,
+/// setting any free variables to zero.
+///
+/// ## Returns
+/// * A solution of the system, or `None` if the system is inconsistent.
+fn solve(rows: &mut [Vec<u8>], unknowns: usize) -> Option<Vec<u8>> {
+    let mut pivots = Vec::with_capacity(unknowns);
+    let mut rank = 0;
+
+    for column in 0..unknowns {
+        // Find a row with a non-zero entry in the current column.
+        let Some(pivot) = (rank..rows.len()).find(|&r| rows[r][column] != 0) else {
+            continue;
+        };
+        rows.swap(rank, pivot);
+
+        // Normalize the pivot row.
+        let factor = rows[rank][column];
+        for value in rows[rank].iter_mut() {
+            *value = ops::div(*value, factor);
+        }
+
+        // Eliminate the current column from every other row.
+        let mut pivot_row = rows[rank].clone();
+        for (r, row) in rows.iter_mut().enumerate() {
+            let factor = row[column];
+            if r == rank || factor == 0 {
+                continue;
+            }
+            for (value, &p) in row[column..].iter_mut().zip(pivot_row[column..].iter()) {
+                *value = ops::add(*value, ops::mult(factor, p));
+            }
+        }
+        pivot_row.zeroize();
+
+        pivots.push(column);
+        rank += 1;
+    }
+
+    // A remaining row of the form `0 = c` with a non-zero `c` renders the system inconsistent.
+    if rows[rank..].iter().any(|row| row[unknowns] != 0) {
+        return None;
+    }
+
+    let mut solution = vec![0u8; unknowns];
+    for (r, &column) in pivots.iter().enumerate() {
+        solution[column] = rows[r][unknowns];
+    }
+    Some(solution)
+}
+
+/// Divides the polynomial `dividend` by the monic polynomial `divisor`,
+/// with coefficients ordered from the constant term up to the highest-degree term.
+///
+/// ## Returns
+/// * The quotient, or `None` if the division leaves a non-zero remainder.
+fn divide(dividend: &mut [u8], divisor: &[u8]) -> Option<Vec<u8>> {
+    let degree = divisor.len() - 1;
+    let mut quotient = vec![0u8; dividend.len() - degree];
+
+    for i in (0..quotient.len()).rev() {
+        // The divisor is monic, so the leading coefficient of the remainder is the next quotient term.
+        let coefficient = dividend[i + degree];
+        quotient[i] = coefficient;
+        for (j, &d) in divisor.iter().enumerate() {
+            dividend[i + j] = ops::add(dividend[i + j], ops::mult(coefficient, d));
+        }
+    }
+
+    if dividend[..degree].iter().any(|&r| r != 0) {
+        quotient.zeroize();
+        return None;
+    }
+    Some(quotient)
+}
+
+// Test cases for the robust reconstruction.
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::split_shares;
+
+    // The 'combine_robust' function without corrupted shares.
+    #[test]
+    fn it_combines_without_corruption() {
+        let secret = b"test_secret";
+        let shares = split_shares(secret, 7, 3).expect("split failed");
+
+        let recovery = combine_robust(&shares, 3).expect("combine failed");
+        assert_eq!(recovery.secret(), secret);
+        assert!(recovery.faulty().is_empty());
+
+        // Exactly `threshold` shares leave no redundancy, but still reconstruct the secret.
+        let recovery = combine_robust(&shares[..3], 3).expect("combine failed");
+        assert_eq!(recovery.into_secret(), secret);
+    }
+
+    // The 'combine_robust' function corrects up to `(n - threshold) / 2` corrupted shares.
+    #[test]
+    fn it_corrects_corrupted_shares() {
+        let secret = b"test_secret";
+        let mut shares = split_shares(secret, 7, 3).expect("split failed");
+
+        // Corrupt two shares at different positions.
+        shares[1].y_mut()[0] ^= 0x5A;
+        shares[5].y_mut()[3] ^= 0xFF;
+        shares[5].y_mut()[10] ^= 0x01;
+
+        let recovery = combine_robust(&shares, 3).expect("combine failed");
+        assert_eq!(recovery.secret(), secret);
+        assert_eq!(recovery.faulty(), &[1, 5]);
+    }
+
+    // The 'combine_robust' function corrects randomly corrupted shares at every position.
+    #[test]
+    fn it_corrects_random_corruptions() {
+        use rand::{seq::SliceRandom, Rng};
+
+        let secret = b"test_secret";
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..20 {
+            let mut shares = split_shares(secret, 9, 3).expect("split failed");
+
+            // Replace the payload of three random shares with random bytes.
+            let mut indices: Vec<usize> = (0..9).collect();
+            indices.shuffle(&mut rng);
+            let mut corrupted = indices[..3].to_vec();
+            corrupted.sort_unstable();
+            for &idx in corrupted.iter() {
+                let original = shares[idx].y().to_vec();
+                while shares[idx].y() == original.as_slice() {
+                    rng.fill(shares[idx].y_mut());
+                }
+            }
+
+            let recovery = combine_robust(&shares, 3).expect("combine failed");
+            assert_eq!(recovery.secret(), secret);
+            assert_eq!(recovery.faulty(), corrupted.as_slice());
+        }
+    }
+
+    // The 'combine_robust' function fails if too many shares are corrupted.
+    #[test]
+    fn it_fails_with_too_many_corrupted_shares() {
+        // Evaluate a known polynomial, since random corruptions could occasionally
+        // lie on a different polynomial of the same degree.
+        let polynomial = Polynomial::from_coefficients(vec![Gf256(0x7C), Gf256(0x3E), Gf256(0x4F)]);
+        let mut shares: Vec<Share> = (1..=5)
+            .map(|x| Share::new(x, vec![polynomial.evaluate(Gf256(x)).0], None))
+            .collect();
+        shares[0].y_mut()[0] ^= 0x01;
+        shares[1].y_mut()[0] ^= 0x02;
+
+        assert_eq!(
+            combine_robust(&shares, 3).unwrap_err(),
+            Error::TooManyFaultyShares { max: 1 }
+        );
+    }
+
+    // The 'combine_robust' function validates the threshold against the shares.
+    #[test]
+    fn it_fails_with_invalid_threshold() {
+        let shares = split_shares(b"test_secret", 5, 3).expect("split failed");
+
+        assert_eq!(
+            combine_robust(&shares, 1).unwrap_err(),
+            Error::ThresholdTooSmall { threshold: 1 }
+        );
+        assert_eq!(
+            combine_robust(&shares, 4).unwrap_err(),
+            Error::ThresholdMismatch {
+                index: 0,
+                expected: 3,
+                found: 4
+            }
+        );
+        assert_eq!(
+            combine_robust(&shares[..2], 3).unwrap_err(),
+            Error::TooFewShares {
+                provided: 2,
+                required: 3
+            }
+        );
+    }
+
+    // The 'combine_robust' function rejects shares at `x = 0`, such as malformed legacy shares.
+    #[test]
+    fn it_fails_with_zero_x_coordinate() {
+        let mut legacy = crate::split(b"test_secret", 5, 3).expect("split failed");
+        *legacy[2].last_mut().expect("empty share") = 0;
+        let shares: Vec<Share> = legacy
+            .iter()
+            .map(|share| Share::try_from(share.as_slice()).expect("parse failed"))
+            .collect();
+
+        assert_eq!(
+            combine_robust(&shares, 3).unwrap_err(),
+            Error::ZeroXCoordinate { index: 2 }
+        );
+    }
+
+    // The secret is redacted from the debug output.
+    #[test]
+    fn it_redacts_debug_output() {
+        let recovery = Recovery {
+            secret: vec![1, 2, 3],
+            faulty: vec![4],
+        };
+        assert_eq!(
+            format!("{recovery:?}"),
+            "Recovery { secret: [REDACTED; 3], faulty: [4] }"
+        );
+    }
+}
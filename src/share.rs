@@ -0,0 +1,542 @@
+use crate::Error;
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(feature = "refresh")]
+use sha2::{Digest, Sha256};
+use subtle::ConstantTimeEq;
+use zeroize::Zeroize;
+
+/// The version of the binary share format written by [`Share::to_bytes`].
+pub const FORMAT_VERSION: u8 = 1;
+
+/// The version of the binary share format written by [`Share::to_bytes`] for shares that were refreshed,
+/// which extends the header with the epoch and the refresh transcript hash.
+pub const EPOCH_FORMAT_VERSION: u8 = 2;
+
+/// The version of the binary share format written by [`Share::to_bytes`] for shares of
+/// [`split_checked_with_rng`](crate::split_checked_with_rng), in the layout of [`EPOCH_FORMAT_VERSION`].
+pub const CHECKED_FORMAT_VERSION: u8 = 3;
+
+/// The length of the header in the binary share format:
+/// version (1 byte), threshold (1 byte), split identifier (4 bytes) and x-coordinate (1 byte).
+pub(crate) const HEADER_LEN: usize = 7;
+
+/// The length of the refresh transcript hash.
+pub(crate) const TRANSCRIPT_LEN: usize = 32;
+
+/// The length of the header in the binary share format of refreshed shares: the header of
+/// [`FORMAT_VERSION`], followed by the epoch (4 bytes) and the refresh transcript hash (32 bytes).
+pub(crate) const EPOCH_HEADER_LEN: usize = HEADER_LEN + 4 + TRANSCRIPT_LEN;
+
+/// The length of the random nonce contributed by every dealer of a refresh to its transcript.
+#[cfg(feature = "refresh")]
+pub(crate) const NONCE_LEN: usize = 16;
+
+/// The tag hashed in front of every refresh transcript.
+#[cfg(feature = "refresh")]
+const TRANSCRIPT_TAG: &[u8] = b"shamirs refresh transcript";
+
+/// Metadata describing the split a share was produced by.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Header {
+    /// Minimum number of shares required to reconstruct the secret.
+    pub(crate) threshold: u8,
+    /// Random identifier shared by all shares of the same split.
+    pub(crate) id: u32,
+    /// The number of refreshes since the split, zero for freshly split shares.
+    pub(crate) epoch: u32,
+    /// The hash chaining the transcripts of all refreshes since the split, zero for freshly split shares.
+    pub(crate) transcript: [u8; TRANSCRIPT_LEN],
+    /// Whether the split embeds a digest share, which only `refresh_checked` preserves.
+    pub(crate) checked: bool,
+}
+
+impl Header {
+    /// Creates the metadata of a fresh split, at epoch zero.
+    pub(crate) fn new(threshold: u8, id: u32) -> Header {
+        Header {
+            threshold,
+            id,
+            epoch: 0,
+            transcript: [0u8; TRANSCRIPT_LEN],
+            checked: false,
+        }
+    }
+
+    /// Creates the metadata of a fresh split in checked mode, at epoch zero.
+    pub(crate) fn new_checked(threshold: u8, id: u32) -> Header {
+        Header {
+            checked: true,
+            ..Header::new(threshold, id)
+        }
+    }
+
+    /// Derives the metadata of the shares after a refresh, at the next epoch.
+    ///
+    /// The transcript hash of the next epoch chains the transcript hash of the current epoch with
+    /// the random nonce of every dealer of the refresh, identified by its x-coordinate. Two independent
+    /// refreshes of the same epoch therefore result in different transcript hashes.
+    #[cfg(feature = "refresh")]
+    pub(crate) fn next_epoch<'a>(&self, nonces: impl IntoIterator<Item = (u8, &'a [u8; NONCE_LEN])>) -> Header {
+        let epoch = self.epoch.wrapping_add(1);
+        let mut hasher = Sha256::new()
+            .chain_update(TRANSCRIPT_TAG)
+            .chain_update(self.transcript)
+            .chain_update(self.id.to_be_bytes())
+            .chain_update([self.threshold])
+            .chain_update(epoch.to_be_bytes());
+        for (x, nonce) in nonces {
+            hasher.update([x]);
+            hasher.update(nonce);
+        }
+
+        Header {
+            epoch,
+            transcript: hasher.finalize().into(),
+            ..*self
+        }
+    }
+
+    /// Checks whether the share was refreshed, which requires the binary format of [`EPOCH_FORMAT_VERSION`].
+    fn is_refreshed(&self) -> bool {
+        self.epoch != 0 || self.transcript != [0u8; TRANSCRIPT_LEN]
+    }
+
+    /// Encodes the x-coordinate and the metadata of a share into the header of the binary share format.
+    ///
+    /// Shares without metadata are written with a threshold and split identifier of zero.
+    pub(crate) fn encode(x: u8, header: Option<Header>) -> [u8; HEADER_LEN] {
+        let header = header.unwrap_or(Header::new(0, 0));
+        let id = header.id.to_be_bytes();
+        [FORMAT_VERSION, header.threshold, id[0], id[1], id[2], id[3], x]
+    }
+
+    /// Decodes the x-coordinate and the metadata of a share from the header of the binary share format.
+    ///
+    /// ## Returns
+    /// * The x-coordinate and the metadata, if any, if successful; otherwise, an error.
+    ///
+    /// ## Errors
+    /// * Returns an error if the format version is unsupported or the threshold is invalid.
+    pub(crate) fn decode(bytes: &[u8; HEADER_LEN]) -> Result<(u8, Option<Header>), Error> {
+        if bytes[0] != FORMAT_VERSION {
+            return Err(Error::UnsupportedVersion { version: bytes[0] });
+        }
+
+        let threshold = bytes[1];
+        let id = u32::from_be_bytes([bytes[2], bytes[3], bytes[4], bytes[5]]);
+        // A threshold of zero marks a share that was written without metadata.
+        let header = match threshold {
+            0 => None,
+            1 => return Err(Error::ThresholdTooSmall { threshold: 1 }),
+            _ => Some(Header::new(threshold, id)),
+        };
+
+        Ok((bytes[6], header))
+    }
+
+    /// Encodes the x-coordinate and the metadata of a refreshed or checked share into the header of the
+    /// binary share format of [`EPOCH_FORMAT_VERSION`] or [`CHECKED_FORMAT_VERSION`].
+    fn encode_epoch(x: u8, header: &Header) -> [u8; EPOCH_HEADER_LEN] {
+        let mut bytes = [0u8; EPOCH_HEADER_LEN];
+        bytes[..HEADER_LEN].copy_from_slice(&Header::encode(x, Some(*header)));
+        bytes[0] = if header.checked {
+            CHECKED_FORMAT_VERSION
+        } else {
+            EPOCH_FORMAT_VERSION
+        };
+        bytes[HEADER_LEN..HEADER_LEN + 4].copy_from_slice(&header.epoch.to_be_bytes());
+        bytes[HEADER_LEN + 4..].copy_from_slice(&header.transcript);
+        bytes
+    }
+
+    /// Decodes the x-coordinate and the metadata of a refreshed or checked share from the header of the
+    /// binary share format of [`EPOCH_FORMAT_VERSION`] or [`CHECKED_FORMAT_VERSION`].
+    ///
+    /// ## Errors
+    /// * Returns an error if the threshold is invalid, since refreshed and checked shares always carry metadata.
+    fn decode_epoch(bytes: &[u8; EPOCH_HEADER_LEN]) -> Result<(u8, Header), Error> {
+        let threshold = bytes[1];
+        if threshold < 2 {
+            return Err(Error::ThresholdTooSmall {
+                threshold: threshold as usize,
+            });
+        }
+
+        let header = Header {
+            threshold,
+            id: u32::from_be_bytes([bytes[2], bytes[3], bytes[4], bytes[5]]),
+            epoch: u32::from_be_bytes([bytes[7], bytes[8], bytes[9], bytes[10]]),
+            transcript: bytes[HEADER_LEN + 4..].try_into().expect("transcript length"),
+            checked: bytes[0] == CHECKED_FORMAT_VERSION,
+        };
+        Ok((bytes[6], header))
+    }
+}
+
+/// A single share of a secret, consisting of an x-coordinate in `GF(2^8)`
+/// and the y-coordinates produced by evaluating each byte's polynomial at that point.
+///
+/// Shares produced by [`split_shares`](crate::split_shares) also carry the threshold and a random
+/// split identifier, which are encoded in the versioned binary format of [`Share::to_bytes`].
+///
+/// The legacy byte layout `(y1, y2, .., yn, x)`, with the x-coordinate stored in the last byte,
+/// is available through the `From` and `TryFrom` conversions and carries no metadata.
+#[derive(Clone)]
+pub struct Share {
+    /// The x-coordinate at which the polynomials were evaluated.
+    x: u8,
+    /// The y-coordinates, one for each byte of the secret.
+    y: Vec<u8>,
+    /// The metadata of the split, absent for shares in the legacy byte layout.
+    header: Option<Header>,
+}
+
+impl Share {
+    /// Creates a new share from its coordinates.
+    ///
+    /// ## Arguments
+    /// * `x` - The x-coordinate of the share.
+    /// * `y` - The y-coordinates of the share.
+    /// * `header` - The metadata of the split, if known.
+    pub(crate) fn new(x: u8, y: Vec<u8>, header: Option<Header>) -> Share {
+        Share { x, y, header }
+    }
+
+    /// Parses a share from the versioned binary format.
+    ///
+    /// ## Arguments
+    /// * `bytes` - The encoded share, as written by [`Share::to_bytes`].
+    ///
+    /// ## Returns
+    /// * The decoded share if successful; otherwise, an error.
+    ///
+    /// ## Errors
+    /// * Returns an error if the format version is unsupported or the share is truncated.
+    ///
+    /// Shares in the legacy byte layout carry no version and are decoded through `TryFrom` instead.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Share, Error> {
+        let version = *bytes.first().ok_or(Error::ShareTooShort { index: 0, len: 0 })?;
+        let header_len = match version {
+            FORMAT_VERSION => HEADER_LEN,
+            EPOCH_FORMAT_VERSION | CHECKED_FORMAT_VERSION => EPOCH_HEADER_LEN,
+            _ => return Err(Error::UnsupportedVersion { version }),
+        };
+        // At least one y-coordinate is required after the header.
+        if bytes.len() <= header_len {
+            return Err(Error::ShareTooShort {
+                index: 0,
+                len: bytes.len(),
+            });
+        }
+
+        let (x, header) = if version == FORMAT_VERSION {
+            Header::decode(bytes[..HEADER_LEN].try_into().expect("header length"))?
+        } else {
+            let (x, header) = Header::decode_epoch(bytes[..EPOCH_HEADER_LEN].try_into().expect("header length"))?;
+            (x, Some(header))
+        };
+
+        Ok(Share {
+            x,
+            y: bytes[header_len..].to_vec(),
+            header,
+        })
+    }
+
+    /// Encodes the share into the versioned binary format
+    /// `(version, threshold, id, x, y1, y2, .., yn)`, with the split identifier in big-endian.
+    ///
+    /// Shares without metadata are written with a threshold and split identifier of zero.
+    ///
+    /// Refreshed shares are written in the format of [`EPOCH_FORMAT_VERSION`]
+    /// `(version, threshold, id, x, epoch, transcript, y1, y2, .., yn)`, with the epoch in big-endian.
+    /// Shares of [`split_checked_with_rng`](crate::split_checked_with_rng) are always written in the same layout,
+    /// with the version [`CHECKED_FORMAT_VERSION`].
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(EPOCH_HEADER_LEN + self.y.len());
+        match self.header {
+            Some(header) if header.is_refreshed() || header.checked => {
+                bytes.extend_from_slice(&Header::encode_epoch(self.x, &header))
+            }
+            header => bytes.extend_from_slice(&Header::encode(self.x, header)),
+        }
+        bytes.extend_from_slice(&self.y);
+        bytes
+    }
+
+    /// Returns the x-coordinate of the share.
+    pub fn x(&self) -> u8 {
+        self.x
+    }
+
+    /// Returns the minimum number of shares required to reconstruct the secret, if known.
+    pub fn threshold(&self) -> Option<usize> {
+        self.header.map(|header| header.threshold as usize)
+    }
+
+    /// Returns the random identifier shared by all shares of the same split, if known.
+    pub fn id(&self) -> Option<u32> {
+        self.header.map(|header| header.id)
+    }
+
+    /// Returns the number of refreshes since the split, if known.
+    ///
+    /// Shares in the legacy byte layout carry no metadata, therefore their epoch is not tracked.
+    pub fn epoch(&self) -> Option<u32> {
+        self.header.map(|header| header.epoch)
+    }
+
+    /// Returns the hash chaining the transcripts of all refreshes since the split, if known.
+    ///
+    /// The hash is zero for shares that were never refreshed.
+    pub fn transcript(&self) -> Option<[u8; TRANSCRIPT_LEN]> {
+        self.header.map(|header| header.transcript)
+    }
+
+    /// Returns `true` if the share was split in checked mode, and must be refreshed with `refresh_checked`.
+    ///
+    /// Shares in the legacy byte layout carry no metadata, therefore checked shares are not recognized.
+    pub fn is_checked(&self) -> bool {
+        matches!(self.header, Some(Header { checked: true, .. }))
+    }
+
+    /// Returns the metadata of the split, if known.
+    pub(crate) fn header(&self) -> Option<Header> {
+        self.header
+    }
+
+    /// Replaces the metadata of the split, as done when the share is refreshed.
+    #[cfg(feature = "refresh")]
+    pub(crate) fn set_header(&mut self, header: Option<Header>) {
+        self.header = header;
+    }
+
+    /// Returns the y-coordinates of the share.
+    pub fn y(&self) -> &[u8] {
+        &self.y
+    }
+
+    /// Returns the length of the payload, which is equal to the length of the secret.
+    pub fn payload_len(&self) -> usize {
+        self.y.len()
+    }
+
+    /// Returns a mutable reference to the y-coordinates of the share.
+    pub(crate) fn y_mut(&mut self) -> &mut [u8] {
+        &mut self.y
+    }
+}
+
+// The y-coordinates are redacted to prevent shares from leaking into logs.
+impl fmt::Debug for Share {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Share")
+            .field("x", &self.x)
+            .field("y", &format_args!("[REDACTED; {}]", self.y.len()))
+            .field("threshold", &self.threshold())
+            .field("id", &self.id())
+            .field("epoch", &self.epoch())
+            .finish()
+    }
+}
+
+// The comparison of the y-coordinates is performed in constant-time,
+// while the lengths and the metadata are considered public information.
+impl PartialEq for Share {
+    fn eq(&self, other: &Self) -> bool {
+        let coordinates = self.x.ct_eq(&other.x) & self.y.as_slice().ct_eq(other.y.as_slice());
+        bool::from(coordinates) && self.header == other.header
+    }
+}
+
+impl Eq for Share {}
+
+// Converts the share into the legacy byte layout `(y1, y2, .., yn, x)`.
+impl From<Share> for Vec<u8> {
+    fn from(share: Share) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(share.y.len() + 1);
+        bytes.extend_from_slice(&share.y);
+        bytes.push(share.x);
+        bytes
+    }
+}
+
+// Parses a share from the legacy byte layout `(y1, y2, .., yn, x)`.
+impl TryFrom<Vec<u8>> for Share {
+    type Error = Error;
+
+    fn try_from(mut bytes: Vec<u8>) -> Result<Share, Error> {
+        // At least one y-coordinate and the x-coordinate are required.
+        if bytes.len() < 2 {
+            return Err(Error::ShareTooShort {
+                index: 0,
+                len: bytes.len(),
+            });
+        }
+        // The x-coordinate is stored in the last byte, the remaining bytes are the y-coordinates.
+        let x = bytes.pop().expect("non-empty share");
+        Ok(Share {
+            x,
+            y: bytes,
+            header: None,
+        })
+    }
+}
+
+// Parses a share from the legacy byte layout `(y1, y2, .., yn, x)`.
+impl TryFrom<&[u8]> for Share {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Share, Error> {
+        Share::try_from(bytes.to_vec())
+    }
+}
+
+// This is important for security purposes to prevent sensitive data
+// from staying in memory after the Share is no longer required and dropped.
+impl Drop for Share {
+    fn drop(&mut self) {
+        // Clear memory associated with the coordinates.
+        self.x.zeroize();
+        self.y.zeroize();
+    }
+}
+
+// Test cases for the `Share` struct.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{format, vec};
+
+    // Conversion from and into the legacy byte layout.
+    #[test]
+    fn it_converts_legacy_layout() {
+        let bytes = vec![137, 206, 171, 244, 50];
+        let share = Share::try_from(bytes.clone()).expect("conversion failed");
+
+        assert_eq!(share.x(), 50);
+        assert_eq!(share.y(), &[137, 206, 171, 244]);
+        assert_eq!(share.payload_len(), 4);
+        assert_eq!(Vec::from(share), bytes);
+    }
+
+    // Conversion fails when the share cannot hold a payload and an x-coordinate.
+    #[test]
+    fn it_fails_to_convert_short_share() {
+        assert_eq!(
+            Share::try_from(&[1u8][..]),
+            Err(Error::ShareTooShort { index: 0, len: 1 })
+        );
+        assert_eq!(
+            Share::try_from(Vec::new()),
+            Err(Error::ShareTooShort { index: 0, len: 0 })
+        );
+    }
+
+    // The y-coordinates are not included in the debug output.
+    #[test]
+    fn it_redacts_debug_output() {
+        let share = Share::new(7, vec![0xAB, 0xCD, 0xEF], Some(Header::new(2, 9)));
+        assert_eq!(
+            format!("{share:?}"),
+            "Share { x: 7, y: [REDACTED; 3], threshold: Some(2), id: Some(9), epoch: Some(0) }"
+        );
+    }
+
+    // Shares are equal only if both coordinates are equal.
+    #[test]
+    fn it_compares_shares() {
+        let share = Share::new(7, vec![1, 2, 3], None);
+
+        assert_eq!(share, Share::new(7, vec![1, 2, 3], None));
+        assert_ne!(share, Share::new(8, vec![1, 2, 3], None));
+        assert_ne!(share, Share::new(7, vec![1, 2, 4], None));
+        assert_ne!(share, Share::new(7, vec![1, 2], None));
+        assert_ne!(share, Share::new(7, vec![1, 2, 3], Some(Header::new(2, 9))));
+    }
+
+    // Encoding and decoding of the versioned binary format.
+    #[test]
+    fn it_encodes_versioned_format() {
+        let share = Share::new(0x2A, vec![1, 2, 3], Some(Header::new(3, 0xDEADBEEF)));
+        let bytes = share.to_bytes();
+        assert_eq!(bytes, vec![FORMAT_VERSION, 3, 0xDE, 0xAD, 0xBE, 0xEF, 0x2A, 1, 2, 3]);
+
+        let decoded = Share::from_bytes(&bytes).expect("decoding failed");
+        assert_eq!(decoded, share);
+        assert_eq!(decoded.threshold(), Some(3));
+        assert_eq!(decoded.id(), Some(0xDEADBEEF));
+    }
+
+    // Checked shares are encoded in the layout of refreshed shares, with their own version.
+    #[test]
+    fn it_encodes_checked_format() {
+        let share = Share::new(0x2A, vec![1, 2, 3], Some(Header::new_checked(3, 9)));
+        assert!(share.is_checked());
+
+        let bytes = share.to_bytes();
+        assert_eq!(bytes.len(), EPOCH_HEADER_LEN + 3);
+        assert_eq!(bytes[..11], [CHECKED_FORMAT_VERSION, 3, 0, 0, 0, 9, 0x2A, 0, 0, 0, 0]);
+
+        let decoded = Share::from_bytes(&bytes).expect("decoding failed");
+        assert_eq!(decoded, share);
+        assert!(decoded.is_checked());
+        assert!(!Share::new(0x2A, vec![1, 2, 3], Some(Header::new(3, 9))).is_checked());
+    }
+
+    // Refreshed shares are encoded with their epoch and transcript hash after the header.
+    #[test]
+    fn it_encodes_epoch_format() {
+        let header = Header {
+            epoch: 3,
+            transcript: [0x5A; TRANSCRIPT_LEN],
+            ..Header::new(2, 9)
+        };
+        let share = Share::new(0x2A, vec![1, 2, 3], Some(header));
+        let bytes = share.to_bytes();
+        assert_eq!(bytes.len(), EPOCH_HEADER_LEN + 3);
+        assert_eq!(bytes[..11], [EPOCH_FORMAT_VERSION, 2, 0, 0, 0, 9, 0x2A, 0, 0, 0, 3]);
+
+        let decoded = Share::from_bytes(&bytes).expect("decoding failed");
+        assert_eq!(decoded, share);
+        assert_eq!(decoded.epoch(), Some(3));
+        assert_eq!(decoded.transcript(), Some([0x5A; TRANSCRIPT_LEN]));
+        assert_eq!(
+            Share::from_bytes(&bytes[..EPOCH_HEADER_LEN]),
+            Err(Error::ShareTooShort {
+                index: 0,
+                len: EPOCH_HEADER_LEN
+            })
+        );
+    }
+
+    // Shares without metadata are encoded with a threshold of zero and decoded without metadata.
+    #[test]
+    fn it_encodes_share_without_metadata() {
+        let share = Share::try_from(vec![1, 2, 3, 0x2A]).expect("conversion failed");
+        let decoded = Share::from_bytes(&share.to_bytes()).expect("decoding failed");
+
+        assert_eq!(decoded, share);
+        assert_eq!(decoded.threshold(), None);
+        assert_eq!(decoded.id(), None);
+    }
+
+    // Decoding fails for unsupported versions and truncated shares.
+    #[test]
+    fn it_fails_to_decode_invalid_format() {
+        assert_eq!(Share::from_bytes(&[]), Err(Error::ShareTooShort { index: 0, len: 0 }));
+        assert_eq!(
+            Share::from_bytes(&[FORMAT_VERSION, 3, 0, 0, 0, 1, 0x2A]),
+            Err(Error::ShareTooShort { index: 0, len: 7 })
+        );
+        assert_eq!(
+            Share::from_bytes(&[0xFF, 3, 0, 0, 0, 1, 0x2A, 1]),
+            Err(Error::UnsupportedVersion { version: 0xFF })
+        );
+        assert_eq!(
+            Share::from_bytes(&[FORMAT_VERSION, 1, 0, 0, 0, 1, 0x2A, 1]),
+            Err(Error::ThresholdTooSmall { threshold: 1 })
+        );
+    }
+}
@@ -0,0 +1,252 @@
+//! Constant-time `GF(2^8)` slice kernels for x86_64, selected at runtime.
+//!
+//! This is the only module of the crate that contains `unsafe` code, which is limited to calling
+//! functions compiled with `#[target_feature]` after the features were detected, and to unaligned
+//! loads and stores within the bounds of the slices.
+//!
+//! * **SSSE3 / AVX2**: The product `a * scalar` is split into `(a & 0x0F) * scalar` and `(a >> 4) * (scalar << 4)`,
+//!   whose 16 possible values are held in a register and selected with `PSHUFB`. The lookups never leave the
+//!   registers, therefore their timing is independent of the looked up values, unlike `LOG/EXP` tables in memory.
+//! * **PCLMULQDQ**: The bytes are spread into 16-bit lanes, multiplied without carries by the scalar
+//!   and reduced with the irreducible polynomial, again with carry-less multiplications.
+//!
+//! Every x86_64 CPU supporting PCLMULQDQ also supports SSSE3, whose kernel processes twice as many bytes
+//! at once. The carry-less kernel is therefore only preferred with the `pclmulqdq` feature, e.g. to test
+//! or benchmark it, and otherwise remains the last resort. The kernel is detected once and cached for the
+//! lifetime of the process.
+#![allow(unsafe_code)]
+#![deny(clippy::undocumented_unsafe_blocks)]
+
+use crate::ops;
+use core::arch::x86_64::*;
+use std::sync::OnceLock;
+
+/// The irreducible polynomial in `GF(2^8)`, without the implicit `x^8` term.
+const IRREDUCIBLE_POLYNOMIAL: i64 = 0x1B;
+
+/// The implementations of the slice kernels, in order of preference.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Kernel {
+    Avx2,
+    Ssse3,
+    Pclmulqdq,
+}
+
+impl Kernel {
+    /// Returns the preferred kernel supported by the running CPU, if any.
+    ///
+    /// The CPU features are only detected on the first call, later calls return the cached kernel.
+    pub(crate) fn detect() -> Option<Kernel> {
+        static KERNEL: OnceLock<Option<Kernel>> = OnceLock::new();
+        *KERNEL.get_or_init(|| {
+            let pclmulqdq = is_x86_feature_detected!("pclmulqdq") && is_x86_feature_detected!("sse2");
+            if cfg!(feature = "pclmulqdq") && pclmulqdq {
+                Some(Kernel::Pclmulqdq)
+            } else if is_x86_feature_detected!("avx2") {
+                Some(Kernel::Avx2)
+            } else if is_x86_feature_detected!("ssse3") {
+                Some(Kernel::Ssse3)
+            } else if pclmulqdq {
+                Some(Kernel::Pclmulqdq)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Returns `true` if the running CPU supports the kernel.
+    #[cfg(test)]
+    fn is_supported(self) -> bool {
+        match self {
+            Kernel::Avx2 => is_x86_feature_detected!("avx2"),
+            Kernel::Ssse3 => is_x86_feature_detected!("ssse3"),
+            Kernel::Pclmulqdq => is_x86_feature_detected!("pclmulqdq") && is_x86_feature_detected!("sse2"),
+        }
+    }
+
+    /// Computes `values[i] = values[i] * scalar + other[i]` if `HORNER` is set,
+    /// otherwise `values[i] = other[i] * scalar + values[i]`, for the longest prefix of whole blocks.
+    ///
+    /// ## Returns
+    /// * The number of elements processed, the remaining elements are left to the scalar fallback.
+    pub(crate) fn mult_xor<const HORNER: bool>(self, values: &mut [u8], other: &[u8], scalar: u8) -> usize {
+        let len = values.len().min(other.len());
+        let (values, other) = (&mut values[..len], &other[..len]);
+        match self {
+            // SAFETY: The kernel is only selected after the CPU features were detected.
+            Kernel::Avx2 => unsafe { mult_xor_avx2::<HORNER>(values, other, scalar) },
+            // SAFETY: The kernel is only selected after the CPU features were detected.
+            Kernel::Ssse3 => unsafe { mult_xor_ssse3::<HORNER>(values, other, scalar) },
+            // SAFETY: The kernel is only selected after the CPU features were detected.
+            Kernel::Pclmulqdq => unsafe { mult_xor_pclmulqdq::<HORNER>(values, other, scalar) },
+        }
+    }
+}
+
+/// Computes the products of the scalar with every value of the low and the high nibble.
+fn nibble_tables(scalar: u8) -> ([u8; 16], [u8; 16]) {
+    let mut low = [0u8; 16];
+    let mut high = [0u8; 16];
+    for (nibble, (low, high)) in low.iter_mut().zip(high.iter_mut()).enumerate() {
+        *low = ops::mult(scalar, nibble as u8);
+        *high = ops::mult(scalar, (nibble as u8) << 4);
+    }
+    (low, high)
+}
+
+/// The split-nibble kernel over blocks of 32 bytes.
+///
+/// # Safety
+/// The CPU must support AVX2, and `values` and `other` must have the same length.
+#[target_feature(enable = "avx2")]
+unsafe fn mult_xor_avx2<const HORNER: bool>(values: &mut [u8], other: &[u8], scalar: u8) -> usize {
+    let (low, high) = nibble_tables(scalar);
+    let low = _mm256_broadcastsi128_si256(_mm_loadu_si128(low.as_ptr() as *const __m128i));
+    let high = _mm256_broadcastsi128_si256(_mm_loadu_si128(high.as_ptr() as *const __m128i));
+    let mask = _mm256_set1_epi8(0x0F);
+
+    let mut processed = 0;
+    for (values, other) in values.chunks_exact_mut(32).zip(other.chunks_exact(32)) {
+        // The chunks are exactly 32 bytes long, which is the width of the unaligned loads and stores.
+        let v = _mm256_loadu_si256(values.as_ptr() as *const __m256i);
+        let o = _mm256_loadu_si256(other.as_ptr() as *const __m256i);
+        let (factor, addend) = if HORNER { (v, o) } else { (o, v) };
+
+        let product = _mm256_xor_si256(
+            _mm256_shuffle_epi8(low, _mm256_and_si256(factor, mask)),
+            _mm256_shuffle_epi8(high, _mm256_and_si256(_mm256_srli_epi16(factor, 4), mask)),
+        );
+        _mm256_storeu_si256(values.as_mut_ptr() as *mut __m256i, _mm256_xor_si256(product, addend));
+        processed += 32;
+    }
+    processed
+}
+
+/// The split-nibble kernel over blocks of 16 bytes.
+///
+/// # Safety
+/// The CPU must support SSSE3, and `values` and `other` must have the same length.
+#[target_feature(enable = "ssse3")]
+unsafe fn mult_xor_ssse3<const HORNER: bool>(values: &mut [u8], other: &[u8], scalar: u8) -> usize {
+    let (low, high) = nibble_tables(scalar);
+    let low = _mm_loadu_si128(low.as_ptr() as *const __m128i);
+    let high = _mm_loadu_si128(high.as_ptr() as *const __m128i);
+    let mask = _mm_set1_epi8(0x0F);
+
+    let mut processed = 0;
+    for (values, other) in values.chunks_exact_mut(16).zip(other.chunks_exact(16)) {
+        // The chunks are exactly 16 bytes long, which is the width of the unaligned loads and stores.
+        let v = _mm_loadu_si128(values.as_ptr() as *const __m128i);
+        let o = _mm_loadu_si128(other.as_ptr() as *const __m128i);
+        let (factor, addend) = if HORNER { (v, o) } else { (o, v) };
+
+        let product = _mm_xor_si128(
+            _mm_shuffle_epi8(low, _mm_and_si128(factor, mask)),
+            _mm_shuffle_epi8(high, _mm_and_si128(_mm_srli_epi16(factor, 4), mask)),
+        );
+        _mm_storeu_si128(values.as_mut_ptr() as *mut __m128i, _mm_xor_si128(product, addend));
+        processed += 16;
+    }
+    processed
+}
+
+/// Multiplies the eight 16-bit lanes of `lanes` by `factor` without carries.
+///
+/// Every lane and the factor must fit into 8 bits, so that the products of at most 15 bits
+/// do not overflow into the neighbouring lanes.
+///
+/// # Safety
+/// The CPU must support PCLMULQDQ and SSE2.
+#[target_feature(enable = "pclmulqdq,sse2")]
+unsafe fn clmul_lanes(lanes: __m128i, factor: __m128i) -> __m128i {
+    let low = _mm_clmulepi64_si128(lanes, factor, 0x00);
+    let high = _mm_clmulepi64_si128(lanes, factor, 0x01);
+    _mm_unpacklo_epi64(low, high)
+}
+
+/// The carry-less multiplication kernel over blocks of 8 bytes.
+///
+/// # Safety
+/// The CPU must support PCLMULQDQ and SSE2, and `values` and `other` must have the same length.
+#[target_feature(enable = "pclmulqdq,sse2")]
+unsafe fn mult_xor_pclmulqdq<const HORNER: bool>(values: &mut [u8], other: &[u8], scalar: u8) -> usize {
+    let scalar = _mm_set_epi64x(0, scalar as i64);
+    let polynomial = _mm_set_epi64x(0, IRREDUCIBLE_POLYNOMIAL);
+    let zero = _mm_setzero_si128();
+    let low_byte = _mm_set1_epi16(0x00FF);
+
+    let mut processed = 0;
+    for (values, other) in values.chunks_exact_mut(8).zip(other.chunks_exact(8)) {
+        // The chunks are exactly 8 bytes long, which is the width of the unaligned loads and stores.
+        let v = _mm_loadl_epi64(values.as_ptr() as *const __m128i);
+        let o = _mm_loadl_epi64(other.as_ptr() as *const __m128i);
+        let (factor, addend) = if HORNER { (v, o) } else { (o, v) };
+
+        // Spread the bytes into 16-bit lanes and multiply them by the scalar, resulting in products of 15 bits.
+        let product = clmul_lanes(_mm_unpacklo_epi8(factor, zero), scalar);
+
+        // Reduce the bits above the 8th with the irreducible polynomial `x^8 = x^4 + x^3 + x + 1`.
+        // The first round leaves at most 12 bits and the second round at most 8 bits.
+        let mut reduced = product;
+        for _ in 0..2 {
+            let overflow = _mm_srli_epi16(reduced, 8);
+            reduced = _mm_xor_si128(_mm_and_si128(reduced, low_byte), clmul_lanes(overflow, polynomial));
+        }
+
+        // Pack the lanes, which fit into 8 bits, back into bytes.
+        let product = _mm_packus_epi16(reduced, zero);
+        _mm_storel_epi64(values.as_mut_ptr() as *mut __m128i, _mm_xor_si128(product, addend));
+        processed += 8;
+    }
+    processed
+}
+
+// Test cases for the SIMD kernels, against the scalar implementation.
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Every supported kernel matches the scalar multiplication, including the elements after the last block.
+    #[test]
+    fn it_matches_scalar_kernel() {
+        let values: [u8; 256 + 37] = core::array::from_fn(|i| (i as u8).wrapping_mul(13));
+        let other: [u8; 256 + 37] = core::array::from_fn(|i| (i as u8).wrapping_mul(31) ^ 0x5A);
+
+        for kernel in [Kernel::Avx2, Kernel::Ssse3, Kernel::Pclmulqdq] {
+            if !kernel.is_supported() {
+                continue;
+            }
+            for scalar in 0..=255 {
+                let mut horner = values;
+                let processed = kernel.mult_xor::<true>(&mut horner, &other, scalar);
+                let mut accumulated = values;
+                assert_eq!(kernel.mult_xor::<false>(&mut accumulated, &other, scalar), processed);
+
+                for i in 0..processed {
+                    assert_eq!(horner[i], ops::mult(values[i], scalar) ^ other[i], "{kernel:?}");
+                    assert_eq!(accumulated[i], ops::mult(other[i], scalar) ^ values[i], "{kernel:?}");
+                }
+                // The remaining elements are left untouched.
+                assert_eq!(horner[processed..], values[processed..]);
+                assert_eq!(accumulated[processed..], values[processed..]);
+            }
+        }
+    }
+
+    // The detected kernel is cached and supported by the running CPU.
+    #[test]
+    fn it_caches_detected_kernel() {
+        let kernel = Kernel::detect();
+        assert_eq!(Kernel::detect(), kernel);
+        if let Some(kernel) = kernel {
+            assert!(kernel.is_supported());
+        }
+
+        // The carry-less kernel is only preferred with the `pclmulqdq` feature.
+        if cfg!(feature = "pclmulqdq") && Kernel::Pclmulqdq.is_supported() {
+            assert_eq!(kernel, Some(Kernel::Pclmulqdq));
+        } else {
+            assert_ne!(kernel, Some(Kernel::Pclmulqdq));
+        }
+    }
+}
@@ -0,0 +1,444 @@
+use crate::{
+    lagrange_weights, ops, polynomial, share::HEADER_LEN, shuffled_x_coordinates, validate_parameters, Error, Header,
+    Share, MAX_PARTS, SPLIT_CHUNK_LEN,
+};
+use rand::{CryptoRng, Rng, RngCore};
+use std::io::{self, Read, Write};
+use zeroize::{Zeroize, Zeroizing};
+
+/// The maximum number of secret bytes processed at once and carried by a single frame of a share stream.
+pub(crate) const STREAM_CHUNK_LEN: usize = 16 * SPLIT_CHUNK_LEN;
+
+/// The length of the big-endian length prefix of every frame.
+pub(crate) const FRAME_PREFIX_LEN: usize = 4;
+
+/// The frame of zero length terminating every share stream.
+pub(crate) const END_FRAME: [u8; FRAME_PREFIX_LEN] = [0; FRAME_PREFIX_LEN];
+
+/// The state of a streaming split, shared by the synchronous and the asynchronous share streams.
+///
+/// The coefficients of the polynomials of a chunk are laid out column-wise as in `split`
+/// and are zeroized as soon as the frames of every share were written, or when the split is dropped.
+pub(crate) struct StreamSplit {
+    /// Minimum number of shares required to reconstruct the secret.
+    threshold: usize,
+    /// The x-coordinates of the shares.
+    x_coordinates: Vec<u8>,
+    /// The metadata written into the header of every share stream.
+    header: Header,
+    /// The coefficients of the polynomials of the current chunk.
+    columns: Vec<u8>,
+    /// The frame of the current chunk for a single share, including the length prefix.
+    frame: Vec<u8>,
+    /// The length of the current chunk.
+    len: usize,
+}
+
+impl StreamSplit {
+    /// Validates the parameters and draws the x-coordinates and the metadata of the shares.
+    ///
+    /// ## Arguments
+    /// * `parts` - Total number of shares to create.
+    /// * `threshold` - Minimum number of shares required to reconstruct the secret.
+    /// * `first_len` - The length of the first chunk, which no further chunk exceeds.
+    /// * `rng` - A cryptographically secure random number generator.
+    ///
+    /// ## Errors
+    /// * Returns an error if parameters are invalid (e.g., `parts` < `threshold`) or the first chunk is empty.
+    pub(crate) fn new<R: RngCore + CryptoRng>(
+        parts: usize,
+        threshold: usize,
+        first_len: usize,
+        rng: &mut R,
+    ) -> Result<StreamSplit, Error> {
+        validate_parameters(parts, threshold, MAX_PARTS)?;
+        if first_len == 0 {
+            return Err(Error::EmptySecret);
+        }
+
+        let mut x_coordinates = shuffled_x_coordinates(MAX_PARTS as u8, rng);
+        x_coordinates.truncate(parts);
+        let header = Header::new(threshold as u8, rng.gen());
+
+        Ok(StreamSplit {
+            threshold,
+            x_coordinates,
+            header,
+            columns: vec![0u8; threshold * first_len],
+            frame: vec![0u8; FRAME_PREFIX_LEN + first_len],
+            len: 0,
+        })
+    }
+
+    /// Returns the header of the stream of the share at `index`, holding its x-coordinate and the metadata.
+    pub(crate) fn header(&self, index: usize) -> [u8; HEADER_LEN] {
+        Header::encode(self.x_coordinates[index], Some(self.header))
+    }
+
+    /// Draws the polynomials of the next chunk of the secret, with the bytes of the chunk as the intercepts.
+    pub(crate) fn load<R: RngCore + CryptoRng>(&mut self, chunk: &[u8], rng: &mut R) {
+        self.len = chunk.len();
+        let columns = &mut self.columns[..self.threshold * chunk.len()];
+        columns[..chunk.len()].copy_from_slice(chunk);
+        rng.fill(&mut columns[chunk.len()..]);
+    }
+
+    /// Evaluates the polynomials of the current chunk at the x-coordinate of the share at `index`.
+    ///
+    /// ## Returns
+    /// * The frame of the share, prefixed with the length of the chunk.
+    pub(crate) fn frame(&mut self, index: usize) -> &[u8] {
+        let (prefix, y) = self.frame[..FRAME_PREFIX_LEN + self.len].split_at_mut(FRAME_PREFIX_LEN);
+        prefix.copy_from_slice(&(self.len as u32).to_be_bytes());
+        polynomial::evaluate_columns(&self.columns[..self.threshold * self.len], self.x_coordinates[index], y);
+        &self.frame[..FRAME_PREFIX_LEN + self.len]
+    }
+
+    /// Clears the coefficients of the current chunk, which include the secret,
+    /// once the frames of every share were written.
+    pub(crate) fn clear(&mut self) {
+        self.columns[..self.threshold * self.len].zeroize();
+        self.len = 0;
+    }
+}
+
+// Clear memory associated with the coefficients and the last frame, also when the stream failed.
+impl Drop for StreamSplit {
+    fn drop(&mut self) {
+        self.columns.zeroize();
+        self.frame.zeroize();
+    }
+}
+
+/// The state of a streaming combine, shared by the synchronous and the asynchronous share streams.
+///
+/// The reconstructed chunk of the secret is zeroized when the combine is dropped.
+pub(crate) struct StreamCombine {
+    /// The Lagrange weights of the shares at `x = 0`.
+    weights: Vec<u8>,
+    /// The y-coordinates of the current frame of a single share.
+    y: Vec<u8>,
+    /// The reconstructed bytes of the current chunk.
+    secret: Vec<u8>,
+    /// The length of the current chunk.
+    len: usize,
+}
+
+impl StreamCombine {
+    /// Validates the headers of the share streams, which are validated as shares without y-coordinates.
+    ///
+    /// ## Errors
+    /// * Returns an error if the share streams are inconsistent or insufficient.
+    pub(crate) fn new(headers: &[[u8; HEADER_LEN]]) -> Result<StreamCombine, Error> {
+        let shares = headers
+            .iter()
+            .map(|header| {
+                let (x, header) = Header::decode(header)?;
+                Ok(Share::new(x, Vec::new(), header))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        Ok(StreamCombine {
+            weights: lagrange_weights(&shares, 0)?,
+            y: vec![0u8; STREAM_CHUNK_LEN],
+            secret: vec![0u8; STREAM_CHUNK_LEN],
+            len: 0,
+        })
+    }
+
+    /// Validates the length prefix of the next frame of the share at `index`, which must be equal
+    /// to the length of the frame of the first share, if any.
+    ///
+    /// ## Returns
+    /// * The length of the frame if successful; otherwise, an error.
+    pub(crate) fn frame_len(
+        prefix: [u8; FRAME_PREFIX_LEN],
+        index: usize,
+        expected: Option<usize>,
+    ) -> io::Result<usize> {
+        let len = u32::from_be_bytes(prefix) as usize;
+        if len > STREAM_CHUNK_LEN {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("share {index} has a frame of {len} bytes, exceeding the maximum of {STREAM_CHUNK_LEN}"),
+            ));
+        }
+        match expected {
+            Some(expected) if expected != len => Err(Error::InconsistentShareLength {
+                index,
+                expected,
+                found: len,
+            }
+            .into()),
+            _ => Ok(len),
+        }
+    }
+
+    /// Starts the reconstruction of a chunk of `len` bytes.
+    pub(crate) fn begin(&mut self, len: usize) {
+        self.len = len;
+        self.secret[..len].fill(0);
+    }
+
+    /// Returns the buffer receiving the payload of the current frame of a share.
+    pub(crate) fn payload(&mut self) -> &mut [u8] {
+        &mut self.y[..self.len]
+    }
+
+    /// Adds the payload of the current frame, weighted by the share at `index`, to the chunk of the secret.
+    pub(crate) fn accumulate(&mut self, index: usize) {
+        ops::mult_accumulate_slice(&mut self.secret[..self.len], self.weights[index], &self.y[..self.len]);
+    }
+
+    /// Returns the reconstructed chunk of the secret.
+    pub(crate) fn secret(&self) -> &[u8] {
+        &self.secret[..self.len]
+    }
+}
+
+// Clear memory associated with the last chunk of the secret and the last frame of a share.
+impl Drop for StreamCombine {
+    fn drop(&mut self) {
+        self.secret.zeroize();
+        self.y.zeroize();
+    }
+}
+
+/// Reads from the reader until the buffer is full or the end of the stream is reached.
+///
+/// ## Returns
+/// * The number of bytes read, which is only less than the length of the buffer at the end of the stream.
+fn read_chunk(reader: &mut impl Read, buffer: &mut [u8]) -> io::Result<usize> {
+    let mut filled = 0;
+    while filled < buffer.len() {
+        match reader.read(&mut buffer[filled..]) {
+            Ok(0) => break,
+            Ok(read) => filled += read,
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => {}
+            Err(error) => return Err(error),
+        }
+    }
+    Ok(filled)
+}
+
+/// Splits a secret read from a stream into multiple share streams, without loading the secret into memory.
+///
+/// Every share stream starts with the header of the binary share format `(version, threshold, id, x)`,
+/// followed by frames of the y-coordinates of a chunk of the secret, each prefixed with its length as
+/// a big-endian `u32`. A frame of zero length terminates the stream, which allows truncated share streams
+/// to be detected. The number of writers determines the number of shares.
+///
+/// ## Arguments
+/// * `reader` - The stream of the secret to be split.
+/// * `writers` - The sinks receiving the share streams, one for each share.
+/// * `threshold` - Minimum number of shares required to reconstruct the secret.
+///
+/// ## Returns
+/// * The number of secret bytes read if successful; otherwise, an error.
+///
+/// ## Errors
+/// * Returns an error of kind `InvalidInput` if parameters are invalid (e.g., `parts` < `threshold`)
+///   or the secret is empty, wrapping the corresponding [`Error`].
+/// * Returns any error of the reader or the writers.
+pub fn split_stream<R: Read, W: Write>(reader: R, writers: &mut [W], threshold: usize) -> io::Result<u64> {
+    split_stream_with_rng(reader, writers, threshold, &mut rand::thread_rng())
+}
+
+/// Splits a secret read from a stream into multiple share streams, drawing randomness from the provided generator.
+///
+/// See [`split_stream`] for the format of the share streams.
+///
+/// ## Arguments
+/// * `reader` - The stream of the secret to be split.
+/// * `writers` - The sinks receiving the share streams, one for each share.
+/// * `threshold` - Minimum number of shares required to reconstruct the secret.
+/// * `rng` - A cryptographically secure random number generator.
+///
+/// ## Returns
+/// * The number of secret bytes read if successful; otherwise, an error.
+///
+/// ## Errors
+/// * Returns an error of kind `InvalidInput` if parameters are invalid (e.g., `parts` < `threshold`)
+///   or the secret is empty, wrapping the corresponding [`Error`].
+/// * Returns any error of the reader or the writers.
+pub fn split_stream_with_rng<R: Read, W: Write, G: RngCore + CryptoRng>(
+    mut reader: R,
+    writers: &mut [W],
+    threshold: usize,
+    rng: &mut G,
+) -> io::Result<u64> {
+    // The chunk of the secret is zeroized when dropped, also when the stream failed.
+    let mut chunk = Zeroizing::new(vec![0u8; STREAM_CHUNK_LEN]);
+
+    let mut len = read_chunk(&mut reader, &mut chunk)?;
+    let mut split = StreamSplit::new(writers.len(), threshold, len, rng)?;
+
+    // The x-coordinate and the metadata of every share are written once, in the header of its stream.
+    for (index, writer) in writers.iter_mut().enumerate() {
+        writer.write_all(&split.header(index))?;
+    }
+
+    let mut total = 0u64;
+    while len > 0 {
+        split.load(&chunk[..len], rng);
+        for (index, writer) in writers.iter_mut().enumerate() {
+            writer.write_all(split.frame(index))?;
+        }
+        split.clear();
+
+        total += len as u64;
+        len = read_chunk(&mut reader, &mut chunk)?;
+    }
+
+    // Terminate every share stream with a frame of zero length.
+    for writer in writers.iter_mut() {
+        writer.write_all(&END_FRAME)?;
+        writer.flush()?;
+    }
+    Ok(total)
+}
+
+/// Combines share streams to reconstruct a secret into a stream, without loading the shares into memory.
+///
+/// The share streams are read frame by frame, as written by [`split_stream`], and every chunk
+/// of the secret is written as soon as it was reconstructed.
+///
+/// ## Arguments
+/// * `readers` - The share streams of the secret.
+/// * `writer` - The sink receiving the secret.
+///
+/// ## Returns
+/// * The number of secret bytes written if successful; otherwise, an error.
+///
+/// ## Errors
+/// * Returns an error of kind `InvalidData` if the share streams are inconsistent or insufficient,
+///   wrapping the corresponding [`Error`], or if a frame is malformed.
+/// * Returns an error of kind `UnexpectedEof` if a share stream is truncated.
+/// * Returns any error of the readers or the writer.
+pub fn combine_stream<R: Read, W: Write>(readers: &mut [R], mut writer: W) -> io::Result<u64> {
+    let mut headers = vec![[0u8; HEADER_LEN]; readers.len()];
+    for (reader, header) in readers.iter_mut().zip(headers.iter_mut()) {
+        reader.read_exact(header)?;
+    }
+    let mut combine = StreamCombine::new(&headers)?;
+
+    let mut total = 0u64;
+    loop {
+        // All share streams must carry frames of the same length.
+        let mut len = None;
+        for (index, reader) in readers.iter_mut().enumerate() {
+            let mut prefix = [0u8; FRAME_PREFIX_LEN];
+            reader.read_exact(&mut prefix)?;
+            len = Some(StreamCombine::frame_len(prefix, index, len)?);
+        }
+        let len = len.unwrap_or(0);
+        if len == 0 {
+            writer.flush()?;
+            return Ok(total);
+        }
+
+        // Reconstruct every byte of the chunk as the weighted sum of the y-coordinates, share by share.
+        combine.begin(len);
+        for (index, reader) in readers.iter_mut().enumerate() {
+            reader.read_exact(combine.payload())?;
+            combine.accumulate(index);
+        }
+
+        writer.write_all(combine.secret())?;
+        total += len as u64;
+    }
+}
+
+// Test cases for the streaming split and combine functions.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::combine_shares;
+    use std::io::Cursor;
+
+    // Splits the secret into share streams held in memory.
+    fn split_into_streams(secret: &[u8], parts: usize, threshold: usize) -> Vec<Vec<u8>> {
+        let mut streams = vec![Vec::new(); parts];
+        let read = split_stream(secret, &mut streams, threshold).expect("split failed");
+        assert_eq!(read, secret.len() as u64);
+        streams
+    }
+
+    // The 'combine_stream' function with share streams from the 'split_stream' function.
+    #[test]
+    fn it_combines_split_streams() {
+        let secret: Vec<u8> = (0..2 * STREAM_CHUNK_LEN + 17).map(|i| (i * 7) as u8).collect();
+        let streams = split_into_streams(&secret, 5, 3);
+
+        // Every stream holds the header and three frames, terminated by a frame of zero length.
+        for stream in streams.iter() {
+            assert_eq!(stream.len(), HEADER_LEN + secret.len() + 4 * FRAME_PREFIX_LEN);
+            assert_eq!(stream[stream.len() - FRAME_PREFIX_LEN..], [0, 0, 0, 0]);
+        }
+
+        let mut readers: Vec<_> = streams[2..].iter().map(Cursor::new).collect();
+        let mut reconstructed = Vec::new();
+        let written = combine_stream(&mut readers, &mut reconstructed).expect("combine failed");
+
+        assert_eq!(written, secret.len() as u64);
+        assert_eq!(reconstructed, secret);
+    }
+
+    // The share streams hold the same y-coordinates as regular shares.
+    #[test]
+    fn it_matches_regular_shares() {
+        let secret = b"test_secret";
+        let streams = split_into_streams(secret, 3, 2);
+
+        let shares: Vec<Share> = streams
+            .iter()
+            .map(|stream| {
+                // Strip the length prefix after the header and the terminating frame.
+                let mut bytes = stream[..HEADER_LEN].to_vec();
+                bytes.extend_from_slice(&stream[HEADER_LEN + FRAME_PREFIX_LEN..stream.len() - FRAME_PREFIX_LEN]);
+                Share::from_bytes(&bytes).expect("decoding failed")
+            })
+            .collect();
+
+        assert_eq!(shares[0].threshold(), Some(2));
+        assert_eq!(combine_shares(&shares[1..]).expect("combine failed"), secret);
+    }
+
+    // Invalid parameters, insufficient, mixed and truncated share streams are reported.
+    #[test]
+    fn it_reports_stream_errors() {
+        let unwrap = |error: io::Error| error.get_ref().and_then(|e| e.downcast_ref::<Error>()).cloned();
+
+        let mut streams = vec![Vec::new(); 2];
+        let error = split_stream(&b""[..], &mut streams, 2).expect_err("empty secret");
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(unwrap(error), Some(Error::EmptySecret));
+
+        let error = split_stream(&b"secret"[..], &mut streams, 3).expect_err("invalid threshold");
+        assert_eq!(
+            unwrap(error),
+            Some(Error::ThresholdExceedsParts { threshold: 3, parts: 2 })
+        );
+
+        let streams = split_into_streams(b"test_secret", 5, 3);
+        let error = combine_stream(&mut [&streams[0][..], &streams[1][..]], io::sink()).expect_err("too few");
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(
+            unwrap(error),
+            Some(Error::TooFewShares {
+                provided: 2,
+                required: 3
+            })
+        );
+
+        let other = split_into_streams(b"test_secret", 5, 3);
+        let error = combine_stream(&mut [&streams[0][..], &streams[1][..], &other[2][..]], io::sink())
+            .expect_err("mixed splits");
+        assert_eq!(unwrap(error), Some(Error::SplitIdMismatch { index: 2 }));
+
+        let truncated = &streams[2][..streams[2].len() - FRAME_PREFIX_LEN];
+        let error =
+            combine_stream(&mut [&streams[0][..], &streams[1][..], truncated], io::sink()).expect_err("truncated");
+        assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof);
+    }
+}
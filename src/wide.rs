@@ -0,0 +1,448 @@
+use crate::{ops16, unique_wide_x_coordinates, validate_parameters, Error, Gf65536, Polynomial, SPLIT_CHUNK_LEN};
+use alloc::{vec, vec::Vec};
+use core::fmt;
+use rand::{CryptoRng, Rng, RngCore};
+use subtle::ConstantTimeEq;
+use zeroize::{Zeroize, Zeroizing};
+
+/// The version of the binary format of wide shares written by [`WideShare::to_bytes`].
+///
+/// The high bit distinguishes wide shares from the shares of [`FORMAT_VERSION`](crate::FORMAT_VERSION).
+pub const WIDE_FORMAT_VERSION: u8 = 0x81;
+
+/// The maximum number of wide shares, bounded by the non-zero elements of `GF(2^16)`.
+pub const MAX_WIDE_PARTS: usize = u16::MAX as usize;
+
+/// The length of the header in the binary format of wide shares: version (1 byte),
+/// threshold (2 bytes), split identifier (4 bytes) and x-coordinate (2 bytes).
+const WIDE_HEADER_LEN: usize = 9;
+
+/// The marker of the ISO/IEC 7816-4 padding, which is followed by zeros up to a whole number of symbols.
+const PADDING_MARKER: u8 = 0x80;
+
+/// The number of 16-bit symbols whose polynomials are generated and evaluated together.
+const WIDE_CHUNK_LEN: usize = SPLIT_CHUNK_LEN / 2;
+
+/// A single share of a secret split over `GF(2^16)`, consisting of a 2-byte x-coordinate and the
+/// y-coordinates produced by evaluating the polynomial of each 16-bit symbol of the padded secret.
+///
+/// Wide shares allow up to 65535 shares for a single secret and always carry the threshold
+/// and a random split identifier, encoded in the binary format of [`WideShare::to_bytes`].
+#[derive(Clone)]
+pub struct WideShare {
+    /// The x-coordinate at which the polynomials were evaluated.
+    x: u16,
+    /// The y-coordinates, two big-endian bytes for each symbol of the padded secret.
+    y: Vec<u8>,
+    /// Minimum number of shares required to reconstruct the secret.
+    threshold: u16,
+    /// Random identifier shared by all shares of the same split.
+    id: u32,
+}
+
+impl WideShare {
+    /// Parses a wide share from its binary format.
+    ///
+    /// ## Arguments
+    /// * `bytes` - The encoded share, as written by [`WideShare::to_bytes`].
+    ///
+    /// ## Returns
+    /// * The decoded share if successful; otherwise, an error.
+    ///
+    /// ## Errors
+    /// * Returns an error if the format version is unsupported, the share is truncated
+    ///   or the payload is not a whole number of 16-bit symbols.
+    pub fn from_bytes(bytes: &[u8]) -> Result<WideShare, Error> {
+        let version = *bytes.first().ok_or(Error::ShareTooShort { index: 0, len: 0 })?;
+        if version != WIDE_FORMAT_VERSION {
+            return Err(Error::UnsupportedVersion { version });
+        }
+        // At least one symbol is required after the header.
+        if bytes.len() < WIDE_HEADER_LEN + 2 {
+            return Err(Error::ShareTooShort {
+                index: 0,
+                len: bytes.len(),
+            });
+        }
+        if (bytes.len() - WIDE_HEADER_LEN) % 2 != 0 {
+            return Err(Error::InvalidPadding);
+        }
+
+        let threshold = u16::from_be_bytes([bytes[1], bytes[2]]);
+        if threshold < 2 {
+            return Err(Error::ThresholdTooSmall {
+                threshold: threshold as usize,
+            });
+        }
+
+        Ok(WideShare {
+            x: u16::from_be_bytes([bytes[7], bytes[8]]),
+            y: bytes[WIDE_HEADER_LEN..].to_vec(),
+            threshold,
+            id: u32::from_be_bytes([bytes[3], bytes[4], bytes[5], bytes[6]]),
+        })
+    }
+
+    /// Encodes the share into the binary format `(version, threshold, id, x, y1, y2, .., yn)`,
+    /// with the threshold, the split identifier, the x-coordinate and every symbol in big-endian.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(WIDE_HEADER_LEN + self.y.len());
+        bytes.push(WIDE_FORMAT_VERSION);
+        bytes.extend_from_slice(&self.threshold.to_be_bytes());
+        bytes.extend_from_slice(&self.id.to_be_bytes());
+        bytes.extend_from_slice(&self.x.to_be_bytes());
+        bytes.extend_from_slice(&self.y);
+        bytes
+    }
+
+    /// Returns the x-coordinate of the share.
+    pub fn x(&self) -> u16 {
+        self.x
+    }
+
+    /// Returns the minimum number of shares required to reconstruct the secret.
+    pub fn threshold(&self) -> usize {
+        self.threshold as usize
+    }
+
+    /// Returns the random identifier shared by all shares of the same split.
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Returns the y-coordinates of the share, two big-endian bytes for each symbol.
+    pub fn y(&self) -> &[u8] {
+        &self.y
+    }
+}
+
+// The y-coordinates are redacted to prevent shares from leaking into logs.
+impl fmt::Debug for WideShare {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WideShare")
+            .field("x", &self.x)
+            .field("y", &format_args!("[REDACTED; {}]", self.y.len()))
+            .field("threshold", &self.threshold)
+            .field("id", &self.id)
+            .finish()
+    }
+}
+
+// The comparison of the y-coordinates is performed in constant-time,
+// while the lengths and the metadata are considered public information.
+impl PartialEq for WideShare {
+    fn eq(&self, other: &Self) -> bool {
+        let coordinates = self.x.ct_eq(&other.x) & self.y.as_slice().ct_eq(other.y.as_slice());
+        bool::from(coordinates) && self.threshold == other.threshold && self.id == other.id
+    }
+}
+
+impl Eq for WideShare {}
+
+// Clear memory associated with the coordinates when the share is dropped.
+impl Drop for WideShare {
+    fn drop(&mut self) {
+        self.x.zeroize();
+        self.y.zeroize();
+    }
+}
+
+/// Splits a secret into multiple shares over `GF(2^16)`, allowing up to 65535 shares.
+///
+/// The secret is padded according to ISO/IEC 7816-4 to a whole number of 16-bit symbols,
+/// and the polynomial of every symbol is evaluated at the 2-byte x-coordinate of each share.
+///
+/// ## Arguments
+/// * `secret` - The secret to be split.
+/// * `parts` - Total number of shares to create.
+/// * `threshold` - Minimum number of shares required to reconstruct the secret.
+///
+/// ## Returns
+/// * A vector of shares if successful; otherwise, an error.
+///
+/// ## Errors
+/// * Returns an error if parameters are invalid (e.g., `parts` < `threshold`) or the secret is empty.
+#[cfg(feature = "std")]
+pub fn split_wide(secret: &[u8], parts: usize, threshold: usize) -> Result<Vec<WideShare>, Error> {
+    split_wide_with_rng(secret, parts, threshold, &mut rand::thread_rng())
+}
+
+/// Splits a secret into multiple shares over `GF(2^16)`, drawing randomness from the provided generator.
+///
+/// See [`split_wide`] for the padding of the secret.
+///
+/// ## Arguments
+/// * `secret` - The secret to be split.
+/// * `parts` - Total number of shares to create.
+/// * `threshold` - Minimum number of shares required to reconstruct the secret.
+/// * `rng` - A cryptographically secure random number generator.
+///
+/// ## Returns
+/// * A vector of shares if successful; otherwise, an error.
+///
+/// ## Errors
+/// * Returns an error if parameters are invalid (e.g., `parts` < `threshold`) or the secret is empty.
+pub fn split_wide_with_rng<R: RngCore + CryptoRng>(
+    secret: &[u8],
+    parts: usize,
+    threshold: usize,
+    rng: &mut R,
+) -> Result<Vec<WideShare>, Error> {
+    // Validate the input parameters.
+    validate_parameters(parts, threshold, MAX_WIDE_PARTS)?;
+    if secret.is_empty() {
+        return Err(Error::EmptySecret);
+    }
+
+    // Pad the secret with the marker and a zero, if required, to a whole number of symbols.
+    let mut padded = Zeroizing::new(Vec::with_capacity(secret.len() + 2));
+    padded.extend_from_slice(secret);
+    padded.push(PADDING_MARKER);
+    if padded.len() % 2 != 0 {
+        padded.push(0);
+    }
+    let symbols = padded.len() / 2;
+
+    // Sample distinct non-zero x-coordinates, without shuffling all elements of the field.
+    let x_coordinates = rand::seq::index::sample(rng, MAX_WIDE_PARTS, parts);
+    let id = rng.gen();
+
+    let mut shares: Vec<_> = x_coordinates
+        .iter()
+        .map(|x| WideShare {
+            x: x as u16 + 1,
+            y: vec![0u8; padded.len()],
+            threshold: threshold as u16,
+            id,
+        })
+        .collect();
+
+    // The coefficients of the polynomials of a chunk of symbols are laid out column-wise as in `split`.
+    let chunk_len = WIDE_CHUNK_LEN.min(symbols);
+    let mut columns = vec![0u16; threshold * chunk_len];
+    let mut values = vec![0u16; chunk_len];
+
+    for offset in (0..symbols).step_by(WIDE_CHUNK_LEN) {
+        let len = chunk_len.min(symbols - offset);
+        let columns = &mut columns[..threshold * len];
+        for (coefficient, symbol) in columns[..len]
+            .iter_mut()
+            .zip(padded[2 * offset..2 * (offset + len)].chunks_exact(2))
+        {
+            *coefficient = u16::from_be_bytes([symbol[0], symbol[1]]);
+        }
+        rng.fill(&mut columns[len..]);
+
+        for share in shares.iter_mut() {
+            // Evaluate the polynomials with Horner's method, from the highest-degree column down to the intercepts.
+            let values = &mut values[..len];
+            let mut highest = columns.chunks_exact(len).rev();
+            values.copy_from_slice(highest.next().expect("threshold of at least two"));
+            for column in highest {
+                ops16::mult_add_slice(values, share.x, column);
+            }
+
+            for (y, value) in share.y[2 * offset..2 * (offset + len)]
+                .chunks_exact_mut(2)
+                .zip(values.iter())
+            {
+                y.copy_from_slice(&value.to_be_bytes());
+            }
+        }
+    }
+
+    // Clear memory associated with the coefficients, which include the secret.
+    columns.zeroize();
+    values.zeroize();
+
+    Ok(shares)
+}
+
+/// Combines shares over `GF(2^16)` to reconstruct the secret, removing its padding.
+///
+/// ## Arguments
+/// * `shares` - Shares of the secret.
+///
+/// ## Returns
+/// * The original secret if successful; otherwise, an error.
+///
+/// ## Errors
+/// * Returns an error if shares are inconsistent or insufficient, or the reconstructed padding is invalid.
+pub fn combine_wide(shares: &[WideShare]) -> Result<Vec<u8>, Error> {
+    let weights = validate_wide_shares(shares)?;
+    let symbols = shares[0].y.len() / 2;
+
+    // Reconstruct every symbol as the weighted sum of the y-coordinates, share by share within chunks.
+    let mut padded = Zeroizing::new(vec![0u8; 2 * symbols]);
+    let chunk_len = WIDE_CHUNK_LEN.min(symbols);
+    let mut sums = vec![0u16; chunk_len];
+    let mut values = vec![0u16; chunk_len];
+
+    for offset in (0..symbols).step_by(WIDE_CHUNK_LEN) {
+        let len = chunk_len.min(symbols - offset);
+        let (sums, values) = (&mut sums[..len], &mut values[..len]);
+        sums.fill(0);
+        for (share, &weight) in shares.iter().zip(weights.iter()) {
+            for (value, y) in values.iter_mut().zip(share.y[2 * offset..].chunks_exact(2)) {
+                *value = u16::from_be_bytes([y[0], y[1]]);
+            }
+            ops16::mult_accumulate_slice(sums, weight, values);
+        }
+
+        for (bytes, sum) in padded[2 * offset..2 * (offset + len)]
+            .chunks_exact_mut(2)
+            .zip(sums.iter())
+        {
+            bytes.copy_from_slice(&sum.to_be_bytes());
+        }
+    }
+    sums.zeroize();
+
+    // Only the last two bytes are inspected, whose positions are public, since the length
+    // of the padding only reveals whether the length of the secret is odd.
+    let len = match padded[padded.len() - 2..] {
+        [_, PADDING_MARKER] => padded.len() - 1,
+        [PADDING_MARKER, 0] => padded.len() - 2,
+        _ => return Err(Error::InvalidPadding),
+    };
+    if len == 0 {
+        return Err(Error::InvalidPadding);
+    }
+
+    Ok(padded[..len].to_vec())
+}
+
+/// Validates that the shares are consistent and sufficient, and computes their Lagrange weights at `x = 0`.
+fn validate_wide_shares(shares: &[WideShare]) -> Result<Vec<u16>, Error> {
+    let first = shares.first().ok_or(Error::TooFewShares {
+        provided: 0,
+        required: 2,
+    })?;
+
+    for (index, share) in shares.iter().enumerate().skip(1) {
+        if share.y.len() != first.y.len() {
+            return Err(Error::InconsistentShareLength {
+                index,
+                expected: first.y.len(),
+                found: share.y.len(),
+            });
+        }
+        if share.id != first.id {
+            return Err(Error::SplitIdMismatch { index });
+        }
+        if share.threshold != first.threshold {
+            return Err(Error::ThresholdMismatch {
+                index,
+                expected: first.threshold(),
+                found: share.threshold(),
+            });
+        }
+    }
+    if shares.len() < first.threshold() {
+        return Err(Error::TooFewShares {
+            provided: shares.len(),
+            required: first.threshold(),
+        });
+    }
+
+    unique_wide_x_coordinates(shares.iter().map(WideShare::x))?;
+
+    let x_samples: Vec<Gf65536> = shares.iter().map(|share| Gf65536(share.x)).collect();
+    let mut weights = vec![Gf65536(0); shares.len()];
+    Polynomial::lagrange_weights(&x_samples, Gf65536(0), &mut weights);
+    Ok(weights.iter().map(|weight| weight.0).collect())
+}
+
+// Test cases for the shares over `GF(2^16)`.
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    // The 'combine_wide' function with shares from the 'split_wide' function, beyond the limit of `GF(2^8)`.
+    #[test]
+    fn it_combines_more_than_255_shares() {
+        let secret = b"test_secret";
+        let shares = split_wide(secret, 1000, 300).expect("split failed");
+        assert_eq!(shares.len(), 1000);
+        assert!(shares.iter().any(|share| share.x() > 255));
+
+        assert_eq!(combine_wide(&shares[..300]).expect("combine failed"), secret);
+        assert_eq!(combine_wide(&shares[700..]).expect("combine failed"), secret);
+    }
+
+    // Secrets of odd and even lengths, spanning multiple chunks, are padded and unpadded.
+    #[test]
+    fn it_pads_secrets_to_symbols() {
+        for len in [1, 2, 3, 2 * WIDE_CHUNK_LEN, 2 * WIDE_CHUNK_LEN + 1] {
+            let secret: Vec<u8> = (0..len).map(|i| (i * 7) as u8 | 0x80).collect();
+            let shares = split_wide(&secret, 5, 3).expect("split failed");
+
+            // The padding always adds one or two bytes to reach a whole number of symbols.
+            assert_eq!(shares[0].y().len(), len + 2 - len % 2);
+            assert_eq!(combine_wide(&shares[2..]).expect("combine failed"), secret);
+        }
+    }
+
+    // Encoding and decoding of the binary format of wide shares.
+    #[test]
+    fn it_encodes_wide_format() {
+        let shares = split_wide(b"test_secret", 3, 2).expect("split failed");
+        let bytes = shares[0].to_bytes();
+        assert_eq!(bytes[..3], [WIDE_FORMAT_VERSION, 0, 2]);
+        assert_eq!(bytes[7..9], shares[0].x().to_be_bytes());
+
+        let decoded = WideShare::from_bytes(&bytes).expect("decoding failed");
+        assert_eq!(decoded, shares[0]);
+        assert_eq!(decoded.id(), shares[0].id());
+
+        assert_eq!(
+            WideShare::from_bytes(&[1, 0, 2]),
+            Err(Error::UnsupportedVersion { version: 1 })
+        );
+        assert_eq!(
+            WideShare::from_bytes(&bytes[..10]),
+            Err(Error::ShareTooShort { index: 0, len: 10 })
+        );
+        assert_eq!(WideShare::from_bytes(&bytes[..12]), Err(Error::InvalidPadding));
+    }
+
+    // Invalid parameters and insufficient or mixed shares are reported.
+    #[test]
+    fn it_reports_wide_errors() {
+        assert_eq!(
+            split_wide(b"secret", 70000, 3),
+            Err(Error::PartsExceedField {
+                parts: 70000,
+                max: MAX_WIDE_PARTS
+            })
+        );
+        assert_eq!(split_wide(b"", 5, 3), Err(Error::EmptySecret));
+
+        let shares = split_wide(b"test_secret", 5, 3).expect("split failed");
+        assert_eq!(
+            combine_wide(&shares[..2]),
+            Err(Error::TooFewShares {
+                provided: 2,
+                required: 3
+            })
+        );
+        let duplicate = [shares[0].clone(), shares[1].clone(), shares[0].clone()];
+        assert_eq!(combine_wide(&duplicate), Err(Error::DuplicateXCoordinate { index: 2 }));
+
+        let other = split_wide(b"test_secret", 5, 3).expect("split failed");
+        let mixed = [shares[0].clone(), shares[1].clone(), other[2].clone()];
+        assert_eq!(combine_wide(&mixed), Err(Error::SplitIdMismatch { index: 2 }));
+    }
+
+    // A decoded share at `x = 0` is reported as such, rather than as a duplicate.
+    #[test]
+    fn it_fails_to_combine_zero_x_coordinate() {
+        let shares = split_wide(b"test_secret", 5, 3).expect("split failed");
+        let mut bytes = shares[1].to_bytes();
+        bytes[7..9].fill(0);
+        let zero = WideShare::from_bytes(&bytes).expect("decoding failed");
+
+        let selected = [shares[0].clone(), zero, shares[2].clone()];
+        assert_eq!(combine_wide(&selected), Err(Error::ZeroXCoordinate { index: 1 }));
+    }
+}